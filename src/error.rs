@@ -0,0 +1,178 @@
+use crate::Span;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParseError {
+    /// In strict mode, a character that merely looks like a Latin letter (e.g. IPA `ɡ` or Greek `ο`) was found.
+    LookalikeCharacter {
+        found: char,
+        looks_like: char,
+        span: Span,
+    },
+
+    /// Something other than a letter (a space, a punctuation, an apostrophe, ...) was found right after an initial.
+    UnexpectedTokenAfterInitial {
+        initial: String,
+        found: String,
+        span: Span,
+    },
+
+    /// An apostrophe was found at the beginning of a word.
+    UnexpectedApostrophe { span: Span },
+
+    /// The string ended right after an initial.
+    UnexpectedEndAfterInitial { initial: String, span: Span },
+
+    /// A word began with a letter that cannot start a syllable, such as `ŋ`, or `i` or `u` outside the full forms
+    /// `iou`, `uei`, `uen` and `ueng`.
+    UnexpectedAlphabetAtWordStart { found: String, span: Span },
+
+    /// No final (-an, -ian, ...) could even be spelled out after the initial.
+    NoCandidateForFinal { initial: String, span: Span },
+
+    /// Some finals could be spelled out after the initial, but none of them fit with what follows.
+    NoAdequateCandidate { candidates: Vec<String>, span: Span },
+
+    /// In strict mode, an apostrophe must be followed by either `a`, `e` or `o`.
+    ApostropheNotFollowedByAEO { span: Span },
+
+    /// In strict mode, a syllable that is spelled out correctly but does not exist in Mandarin, such as `fi`,
+    /// was found; see [`crate::is_valid_syllable`].
+    InvalidSyllable { syllable: String, span: Span },
+
+    /// Zhuyin that corresponds to no syllable of pinyin, such as `ㄅ` or `ㄅㄧㄞ`, was found.
+    InvalidZhuyin { found: String, span: Span },
+
+    /// Letters that cannot be split into syllables of Wade–Giles were found.
+    InvalidWadeGiles { found: String, span: Span },
+
+    /// Letters that cannot be split into syllables of Tongyong Pinyin were found.
+    InvalidTongyong { found: String, span: Span },
+
+    /// A tone number that follows no vowel, or follows one that already bears a tone, such as `4` in `x4` or `hǎo3`,
+    /// was found.
+    MisplacedToneNumber { found: String, span: Span },
+
+    /// In strict mode, a final was written in its full form, such as `liou` for `liu` or `uen` for `wen`.
+    FullFormFinal {
+        full_form: String,
+        standard: String,
+        span: Span,
+    },
+}
+
+impl ParseError {
+    /// Where in the original string the error was found
+    #[must_use]
+    pub const fn span(&self) -> &Span {
+        match self {
+            Self::LookalikeCharacter { span, .. }
+            | Self::UnexpectedTokenAfterInitial { span, .. }
+            | Self::UnexpectedApostrophe { span }
+            | Self::UnexpectedEndAfterInitial { span, .. }
+            | Self::UnexpectedAlphabetAtWordStart { span, .. }
+            | Self::NoCandidateForFinal { span, .. }
+            | Self::NoAdequateCandidate { span, .. }
+            | Self::ApostropheNotFollowedByAEO { span }
+            | Self::InvalidSyllable { span, .. }
+            | Self::InvalidZhuyin { span, .. }
+            | Self::InvalidWadeGiles { span, .. }
+            | Self::InvalidTongyong { span, .. }
+            | Self::MisplacedToneNumber { span, .. }
+            | Self::FullFormFinal { span, .. } => span,
+        }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::LookalikeCharacter {
+                found, looks_like, ..
+            } => {
+                write!(f, "'{found}' looks like '{looks_like}', but it is not.")
+            }
+            Self::UnexpectedTokenAfterInitial { initial, found, .. } => {
+                write!(f, "unexpected `{found}` found after parsing initial `{initial}`")
+            }
+            Self::UnexpectedApostrophe { .. } => {
+                write!(f, "unexpected apostrophe found at the beginning of a word")
+            }
+            Self::UnexpectedEndAfterInitial { initial, .. } => {
+                write!(f, "unexpected end of string found after `{initial}`")
+            }
+            Self::UnexpectedAlphabetAtWordStart { found, .. } => {
+                write!(f, "unexpected alphabet `{found}` found at the beginning of a word")
+            }
+            Self::NoCandidateForFinal { initial, .. } => write!(
+                f,
+                "no adequate candidate for finals (-an, -ian, ...) is found, after the initial `{initial}`"
+            ),
+            Self::NoAdequateCandidate { candidates, .. } => write!(
+                f,
+                "no adequate candidate for finals (-an, -ian, ...) found, among possible candidates {candidates:?}"
+            ),
+            Self::ApostropheNotFollowedByAEO { .. } => write!(
+                f,
+                "In strict mode, an apostrophe must be followed by either 'a', 'e' or 'o'"
+            ),
+            Self::InvalidSyllable { syllable, .. } => {
+                write!(f, "`{syllable}` is not a syllable of Mandarin")
+            }
+            Self::InvalidZhuyin { found, .. } => {
+                write!(f, "`{found}` is not a syllable of Zhuyin")
+            }
+            Self::InvalidWadeGiles { found, .. } => {
+                write!(f, "`{found}` cannot be read as Wade–Giles")
+            }
+            Self::InvalidTongyong { found, .. } => {
+                write!(f, "`{found}` cannot be read as Tongyong Pinyin")
+            }
+            Self::MisplacedToneNumber { found, .. } => {
+                write!(f, "the tone number `{found}` does not follow a syllable that can bear it")
+            }
+            Self::FullFormFinal {
+                full_form,
+                standard,
+                ..
+            } => {
+                write!(f, "`{full_form}` is written in full; the standard spelling is `{standard}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// An error in a table given to [`crate::RomanizationTable`]. Lines are counted from 1.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TableError {
+    /// A line has a key, but nothing to write the syllable as.
+    MissingValue { line: usize },
+
+    /// The key of a line is not a single syllable of toneless pinyin, optionally followed by a tone number from 1 to 5.
+    InvalidKey { line: usize, key: String },
+
+    /// A directive other than `@separator` or `@erhua` was found.
+    UnknownDirective { line: usize, directive: String },
+}
+
+impl std::fmt::Display for TableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::MissingValue { line } => {
+                write!(f, "line {line}: nothing to write the syllable as")
+            }
+            Self::InvalidKey { line, key } => {
+                write!(
+                    f,
+                    "line {line}: `{key}` is not a syllable of toneless pinyin"
+                )
+            }
+            Self::UnknownDirective { line, directive } => {
+                write!(f, "line {line}: unknown directive `{directive}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TableError {}
@@ -1,4 +1,4 @@
-use crate::{pinyin_token, Strictness, VecAndIndex};
+use crate::{pinyin_token, ParseError, SpellingInitial, Strictness, VecAndIndex};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum NonRhoticFinal {
@@ -39,17 +39,71 @@ pub enum NonRhoticFinal {
     Üan,
     Üe,
     Ün,
+
+    /// syllabic `m`, as in `ḿ`
+    M,
+
+    /// syllabic `n`, as in `ń`
+    N,
 }
 
 impl NonRhoticFinal {
+    /// Whether the spelling begins with `i`, `u` or `ü`, which are written as `y` or `w` at the beginning of a syllable
     #[must_use]
-    pub const fn len(self) -> usize {
+    pub(crate) const fn begins_with_medial(self) -> bool {
+        !matches!(
+            self,
+            Self::A
+                | Self::Ai
+                | Self::An
+                | Self::Ang
+                | Self::Ao
+                | Self::E
+                | Self::Ê
+                | Self::Ei
+                | Self::En
+                | Self::Eng
+                | Self::O
+                | Self::Ong
+                | Self::Ou
+                | Self::M
+                | Self::N
+        )
+    }
+
+    /// Whether the final begins with `i` or `ü`, as the finals after `j`, `q` and `x` do
+    #[must_use]
+    pub(crate) const fn is_palatal(self) -> bool {
+        matches!(
+            self,
+            Self::I
+                | Self::Ia
+                | Self::Io
+                | Self::Ie
+                | Self::Iao
+                | Self::Iu
+                | Self::Ian
+                | Self::In
+                | Self::Iang
+                | Self::Ing
+                | Self::Iong
+                | Self::Ü
+                | Self::Ue
+                | Self::Üe
+                | Self::Üan
+                | Self::Ün
+        )
+    }
+
+    /// The number of letters it takes to spell the final
+    #[must_use]
+    pub(crate) const fn len(self) -> usize {
         use NonRhoticFinal::{
             Ai, An, Ang, Ao, Ei, En, Eng, Ia, Ian, Iang, Iao, Ie, In, Ing, Io, Iong, Iu, Ong, Ou,
-            Ua, Uai, Uan, Uang, Ue, Ui, Un, Uo, Üan, Üe, Ün, A, E, I, O, U, Ê, Ü,
+            Ua, Uai, Uan, Uang, Ue, Ui, Un, Uo, Üan, Üe, Ün, A, E, I, M, N, O, U, Ê, Ü,
         };
         match self {
-            A | E | Ê | I | O | U | Ü => 1,
+            A | E | Ê | I | O | U | Ü | M | N => 1,
             Ai | An | Ao | Ei | En | Ia | Ie | In | Iu | Io | Ou | Ua | Ue | Ui | Un | Uo | Üe
             | Ün => 2,
             Ang | Eng | Ian | Iao | Ing | Ong | Uai | Uan | Üan => 3,
@@ -58,6 +112,7 @@ impl NonRhoticFinal {
     }
 }
 
+/// `Fifth` stands for the light tone
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Tone {
     First,
@@ -67,10 +122,27 @@ pub enum Tone {
     Fifth,
 }
 
+/// The tone marked by a single diacritic, as on `ḿ` or `ń`
+#[must_use]
+pub fn tone_of_lone_diacritic(
+    diacritics: &[pinyin_token::Diacritic],
+    strictness: Strictness,
+) -> Option<Tone> {
+    use pinyin_token::Diacritic;
+    match diacritics {
+        [Diacritic::Macron] => Some(Tone::First),
+        [Diacritic::Acute] => Some(Tone::Second),
+        [Diacritic::Hacek] => Some(Tone::Third),
+        [Diacritic::Breve] if !strictness.is_strict() => Some(Tone::Third),
+        [Diacritic::Grave] => Some(Tone::Fourth),
+        _ => None,
+    }
+}
+
 macro_rules! toneless {
     ($self_:expr, $ind:expr, $alphabet_pat:pat) => {
         match $self_.vec.get($self_.next_pos + $ind) {
-            Some(PinyinToken::Alph(alph)) => {
+            Some(Ok(PinyinToken::Alph(alph))) => {
                 matches!(alph.alphabet, $alphabet_pat) && alph.diacritics.is_empty()
             }
             _ => false,
@@ -79,7 +151,7 @@ macro_rules! toneless {
 
     ($self_:expr, $ind:expr, $alphabet_pat:pat, $diacritic_pat:pat) => {
         match $self_.vec.get($self_.next_pos + $ind) {
-            Some(PinyinToken::Alph(alph)) => {
+            Some(Ok(PinyinToken::Alph(alph))) => {
                 matches!(alph.alphabet, $alphabet_pat)
                     && matches!(&alph.diacritics[..], &[$diacritic_pat])
             }
@@ -91,7 +163,7 @@ macro_rules! toneless {
 macro_rules! tone {
     ($self_:expr, $strictness: expr, $ind:expr, $alphabet_pat:pat) => {
         match $self_.vec.get($self_.next_pos + $ind) {
-            Some(PinyinToken::Alph(alph)) => {
+            Some(Ok(PinyinToken::Alph(alph))) => {
                 if matches!(alph.alphabet, $alphabet_pat) {
                     match &alph.diacritics[..] {
                         &[Diacritic::Macron] => Some(Tone::First),
@@ -118,9 +190,10 @@ macro_rules! tone {
 
     ($self_:expr, $strictness: expr, $ind:expr, $alphabet_pat:pat, $diacritic_pat:pat) => {
         match $self_.vec.get($self_.next_pos + $ind) {
-            Some(PinyinToken::Alph(alph)) => {
+            Some(Ok(PinyinToken::Alph(alph))) => {
                 if matches!(alph.alphabet, $alphabet_pat) {
                     match &alph.diacritics[..] {
+                        &[$diacritic_pat] => Some(Tone::Fifth),
                         &[$diacritic_pat, Diacritic::Macron] => Some(Tone::First),
                         &[$diacritic_pat, Diacritic::Acute] => Some(Tone::Second),
                         &[$diacritic_pat, Diacritic::Hacek] => Some(Tone::Third),
@@ -159,6 +232,143 @@ pub struct Candidate {
     pub ŋ: bool,
     pub fin: NonRhoticFinal,
     pub tone: Tone,
+
+    /// Whether the final is written in its full form, such as `iou` for `iu` or `ueng` for `ong`
+    pub full_form: bool,
+}
+
+impl Candidate {
+    /// The number of letters the candidate takes up
+    pub const fn len(self) -> usize {
+        // ŋ accounts for ng, and the full form has one letter more
+        self.fin.len() - self.ŋ as usize + self.full_form as usize
+    }
+
+    /// Whether the candidate may follow `initial`; `juen`, for instance, is not the full form of `jun`,
+    /// `ueng` only stands on its own, and `ing` does not stand on its own.
+    pub const fn fits(self, initial: SpellingInitial) -> bool {
+        use SpellingInitial as I;
+        match (self.full_form, self.fin) {
+            (false, fin) => !(matches!(initial, I::ZeroAEO) && fin.begins_with_medial()),
+            (true, NonRhoticFinal::Iu) => !matches!(initial, I::Y | I::W),
+            (true, NonRhoticFinal::Ong) => matches!(initial, I::ZeroAEO),
+            (true, _) => !matches!(initial, I::Y | I::W | I::J | I::Q | I::X),
+        }
+    }
+
+    /// The initial and the final as spelled in standard pinyin.
+    /// A syllable without an initial may have been written in its full form, such as `uen` for `wen`.
+    pub const fn standard(self, initial: SpellingInitial) -> (SpellingInitial, NonRhoticFinal) {
+        match initial {
+            SpellingInitial::ZeroAEO if self.full_form => {
+                spelled(None, underlying(initial, self.fin))
+            }
+            _ => (initial, self.fin),
+        }
+    }
+}
+
+/// The final as pronounced, with the `y`, `w` and the `u` standing for `ü` taken into account.
+/// `yai` is left as it is, since there is no `NonRhoticFinal` for it.
+pub const fn underlying(initial: SpellingInitial, fin: NonRhoticFinal) -> NonRhoticFinal {
+    use NonRhoticFinal as F;
+    use SpellingInitial as I;
+    match (initial, fin) {
+        (I::J | I::Q | I::X | I::Y, F::U) => F::Ü,
+        (I::J | I::Q | I::X | I::Y, F::Un) => F::Ün,
+        (I::J | I::Q | I::X | I::Y, F::Uan) => F::Üan,
+        (_, F::Ue) => F::Üe,
+        (I::Y, F::A) => F::Ia,
+        (I::Y, F::O) => F::Io,
+        (I::Y, F::E) => F::Ie,
+        (I::Y, F::Ao) => F::Iao,
+        (I::Y, F::Ou) => F::Iu,
+        (I::Y, F::An) => F::Ian,
+        (I::Y, F::Ang) => F::Iang,
+        (I::Y, F::Ong) => F::Iong,
+        (I::W, F::A) => F::Ua,
+        (I::W, F::O) => F::Uo,
+        (I::W, F::Ai) => F::Uai,
+        (I::W, F::Ei) => F::Ui,
+        (I::W, F::An) => F::Uan,
+        (I::W, F::En) => F::Un,
+        (I::W, F::Ang) => F::Uang,
+        (I::W, F::Eng) => F::Ong,
+        (_, fin) => fin,
+    }
+}
+
+/// The inverse of [`underlying`]; spells out `initial` and the final as pronounced in pinyin.
+/// `None` stands for the lack of an initial.
+pub const fn spelled(
+    initial: Option<SpellingInitial>,
+    fin: NonRhoticFinal,
+) -> (SpellingInitial, NonRhoticFinal) {
+    use NonRhoticFinal as F;
+    use SpellingInitial as I;
+    match (initial, fin) {
+        (None, F::I | F::In | F::Ing) => (I::Y, fin),
+        (None, F::Ia) => (I::Y, F::A),
+        (None, F::Io) => (I::Y, F::O),
+        (None, F::Ie) => (I::Y, F::E),
+        (None, F::Iao) => (I::Y, F::Ao),
+        (None, F::Iu) => (I::Y, F::Ou),
+        (None, F::Ian) => (I::Y, F::An),
+        (None, F::Iang) => (I::Y, F::Ang),
+        (None, F::Iong) => (I::Y, F::Ong),
+        (None, F::U) => (I::W, F::U),
+        (None, F::Ua) => (I::W, F::A),
+        (None, F::Uo) => (I::W, F::O),
+        (None, F::Uai) => (I::W, F::Ai),
+        (None, F::Ui) => (I::W, F::Ei),
+        (None, F::Uan) => (I::W, F::An),
+        (None, F::Un) => (I::W, F::En),
+        (None, F::Uang) => (I::W, F::Ang),
+        (None, F::Ong) => (I::W, F::Eng),
+        (None, F::Ü) => (I::Y, F::U),
+        (None, F::Ue | F::Üe) => (I::Y, F::Ue),
+        (None, F::Üan) => (I::Y, F::Uan),
+        (None, F::Ün) => (I::Y, F::Un),
+        (None, _) => (I::ZeroAEO, fin),
+        (Some(i @ (I::J | I::Q | I::X)), F::Ü) => (i, F::U),
+        (Some(i @ (I::J | I::Q | I::X)), F::Üe) => (i, F::Ue),
+        (Some(i @ (I::J | I::Q | I::X)), F::Üan) => (i, F::Uan),
+        (Some(i @ (I::J | I::Q | I::X)), F::Ün) => (i, F::Un),
+        (Some(i), _) => (i, fin),
+    }
+}
+
+/// Puts the tone mark on the first of `a`, `e`, `ê` and `o` in `spelling`, or else on its last `i`, `u` or `ü`,
+/// or else on its last letter
+pub fn with_tone_mark(spelling: &str, tone: Tone) -> String {
+    let mark = match tone {
+        Tone::First => "\u{304}",
+        Tone::Second => "\u{301}",
+        Tone::Third => "\u{30c}",
+        Tone::Fourth => "\u{300}",
+        Tone::Fifth => "",
+    };
+    with_mark(spelling, mark)
+}
+
+/// Puts the combining `mark` where [`with_tone_mark`] would
+pub fn with_mark(spelling: &str, mark: &str) -> String {
+    use unicode_normalization::UnicodeNormalization;
+    let position = spelling
+        .find(['a', 'e', 'ê', 'o'])
+        .or_else(|| spelling.rfind(['i', 'u', 'ü']))
+        .or_else(|| spelling.char_indices().last().map(|(i, _)| i));
+    let Some(position) = position else {
+        return spelling.to_owned();
+    };
+    let end = position
+        + spelling[position..]
+            .chars()
+            .next()
+            .map_or(0, char::len_utf8);
+    format!("{}{mark}{}", &spelling[..end], &spelling[end..])
+        .nfc()
+        .collect()
 }
 
 pub struct FinalWithTone {
@@ -207,6 +417,8 @@ impl std::fmt::Display for FinalWithTone {
             NonRhoticFinal::Üan => ("üa", "n"),
             NonRhoticFinal::Üe => ("üe", ""),
             NonRhoticFinal::Ün => ("ü", "n"),
+            NonRhoticFinal::M => ("m", ""),
+            NonRhoticFinal::N => ("n", ""),
         };
 
         let b = match self.tone {
@@ -223,7 +435,7 @@ impl std::fmt::Display for FinalWithTone {
     }
 }
 
-impl VecAndIndex<pinyin_token::PinyinToken> {
+impl VecAndIndex<Result<pinyin_token::PinyinToken, ParseError>> {
     #[must_use]
     #[allow(clippy::too_many_lines)]
     #[allow(clippy::cognitive_complexity)]
@@ -236,6 +448,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                 ŋ: false,
                 fin: NonRhoticFinal::A,
                 tone,
+                full_form: false,
             });
 
             if toneless!(self, 1, Alphabet::I) {
@@ -243,6 +456,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Ai,
                     tone,
+                    full_form: false,
                 });
             }
 
@@ -251,6 +465,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::An,
                     tone,
+                    full_form: false,
                 });
             }
 
@@ -259,6 +474,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: true,
                     fin: NonRhoticFinal::Ang,
                     tone,
+                    full_form: false,
                 });
             }
 
@@ -267,6 +483,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Ang,
                     tone,
+                    full_form: false,
                 });
             }
 
@@ -275,6 +492,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Ao,
                     tone,
+                    full_form: false,
                 });
             }
         }
@@ -284,6 +502,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                 ŋ: false,
                 fin: NonRhoticFinal::Ê,
                 tone,
+                full_form: false,
             });
         }
 
@@ -292,6 +511,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                 ŋ: false,
                 fin: NonRhoticFinal::E,
                 tone,
+                full_form: false,
             });
 
             if toneless!(self, 1, Alphabet::I) {
@@ -299,6 +519,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Ei,
                     tone,
+                    full_form: false,
                 });
             }
 
@@ -307,6 +528,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::En,
                     tone,
+                    full_form: false,
                 });
             }
 
@@ -315,6 +537,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Eng,
                     tone,
+                    full_form: false,
                 });
             }
 
@@ -323,6 +546,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: true,
                     fin: NonRhoticFinal::Eng,
                     tone,
+                    full_form: false,
                 });
             }
         }
@@ -332,6 +556,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                 ŋ: false,
                 fin: NonRhoticFinal::O,
                 tone,
+                full_form: false,
             });
 
             if toneless!(self, 1, Alphabet::Ŋ) {
@@ -339,6 +564,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: true,
                     fin: NonRhoticFinal::Ong,
                     tone,
+                    full_form: false,
                 });
             }
 
@@ -347,6 +573,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Ong,
                     tone,
+                    full_form: false,
                 });
             }
 
@@ -355,6 +582,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Ou,
                     tone,
+                    full_form: false,
                 });
             }
         }
@@ -367,6 +595,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                 ŋ: false,
                 fin: NonRhoticFinal::I,
                 tone,
+                full_form: false,
             });
 
             if toneless!(self, 1, Alphabet::N) {
@@ -374,6 +603,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::In,
                     tone,
+                    full_form: false,
                 });
             }
 
@@ -382,6 +612,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Ing,
                     tone,
+                    full_form: false,
                 });
             }
 
@@ -390,6 +621,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: true,
                     fin: NonRhoticFinal::Ing,
                     tone,
+                    full_form: false,
                 });
             }
         }
@@ -401,6 +633,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Ia,
                     tone,
+                    full_form: false,
                 });
 
                 if toneless!(self, 2, Alphabet::N) {
@@ -408,6 +641,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                         ŋ: false,
                         fin: NonRhoticFinal::Ian,
                         tone,
+                        full_form: false,
                     });
                 }
 
@@ -416,6 +650,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                         ŋ: false,
                         fin: NonRhoticFinal::Iang,
                         tone,
+                        full_form: false,
                     });
                 }
 
@@ -424,6 +659,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                         ŋ: true,
                         fin: NonRhoticFinal::Iang,
                         tone,
+                        full_form: false,
                     });
                 }
 
@@ -432,6 +668,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                         ŋ: false,
                         fin: NonRhoticFinal::Iao,
                         tone,
+                        full_form: false,
                     });
                 }
             } // end -ia..
@@ -441,6 +678,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Ie,
                     tone,
+                    full_form: false,
                 });
             }
 
@@ -449,6 +687,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Iu,
                     tone,
+                    full_form: false,
                 });
             }
 
@@ -457,12 +696,22 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Io,
                     tone,
+                    full_form: false,
                 });
+                if toneless!(self, 2, Alphabet::U) {
+                    ans.push(Candidate {
+                        ŋ: false,
+                        fin: NonRhoticFinal::Iu,
+                        tone,
+                        full_form: true,
+                    });
+                }
                 if toneless!(self, 2, Alphabet::N) && toneless!(self, 3, Alphabet::G) {
                     ans.push(Candidate {
                         ŋ: false,
                         fin: NonRhoticFinal::Iong,
                         tone,
+                        full_form: false,
                     });
                 }
 
@@ -471,6 +720,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                         ŋ: true,
                         fin: NonRhoticFinal::Iong,
                         tone,
+                        full_form: false,
                     });
                 }
             }
@@ -481,6 +731,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                 ŋ: false,
                 fin: NonRhoticFinal::U,
                 tone,
+                full_form: false,
             });
 
             if toneless!(self, 1, Alphabet::N) {
@@ -488,6 +739,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Un,
                     tone,
+                    full_form: false,
                 });
             }
         }
@@ -499,6 +751,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Ua,
                     tone,
+                    full_form: false,
                 });
 
                 if toneless!(self, 2, Alphabet::I) {
@@ -506,6 +759,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                         ŋ: false,
                         fin: NonRhoticFinal::Uai,
                         tone,
+                        full_form: false,
                     });
                 }
 
@@ -514,6 +768,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                         ŋ: false,
                         fin: NonRhoticFinal::Uan,
                         tone,
+                        full_form: false,
                     });
                 }
 
@@ -522,6 +777,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                         ŋ: false,
                         fin: NonRhoticFinal::Uang,
                         tone,
+                        full_form: false,
                     });
                 }
 
@@ -530,6 +786,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                         ŋ: true,
                         fin: NonRhoticFinal::Uang,
                         tone,
+                        full_form: false,
                     });
                 }
             } // end -ua..
@@ -539,7 +796,45 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Ue,
                     tone,
+                    full_form: false,
                 });
+
+                // the full forms `uei`, `uen` and `ueng`
+                if toneless!(self, 2, Alphabet::I) {
+                    ans.push(Candidate {
+                        ŋ: false,
+                        fin: NonRhoticFinal::Ui,
+                        tone,
+                        full_form: true,
+                    });
+                }
+
+                if toneless!(self, 2, Alphabet::N) {
+                    ans.push(Candidate {
+                        ŋ: false,
+                        fin: NonRhoticFinal::Un,
+                        tone,
+                        full_form: true,
+                    });
+                }
+
+                if toneless!(self, 2, Alphabet::N) && toneless!(self, 3, Alphabet::G) {
+                    ans.push(Candidate {
+                        ŋ: false,
+                        fin: NonRhoticFinal::Ong,
+                        tone,
+                        full_form: true,
+                    });
+                }
+
+                if toneless!(self, 2, Alphabet::Ŋ) {
+                    ans.push(Candidate {
+                        ŋ: true,
+                        fin: NonRhoticFinal::Ong,
+                        tone,
+                        full_form: true,
+                    });
+                }
             }
 
             if let Some(tone) = tone!(self, strictness, 1, Alphabet::I) {
@@ -547,6 +842,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Ui,
                     tone,
+                    full_form: false,
                 });
             }
 
@@ -555,6 +851,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Uo,
                     tone,
+                    full_form: false,
                 });
             }
         }
@@ -564,6 +861,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                 ŋ: false,
                 fin: NonRhoticFinal::Ü,
                 tone,
+                full_form: false,
             });
 
             if toneless!(self, 1, Alphabet::N) {
@@ -571,6 +869,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Ün,
                     tone,
+                    full_form: false,
                 });
             }
         }
@@ -581,6 +880,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                         ŋ: false,
                         fin: NonRhoticFinal::Üan,
                         tone,
+                        full_form: false,
                     });
                 }
             }
@@ -590,6 +890,7 @@ impl VecAndIndex<pinyin_token::PinyinToken> {
                     ŋ: false,
                     fin: NonRhoticFinal::Üe,
                     tone,
+                    full_form: false,
                 });
             }
         }
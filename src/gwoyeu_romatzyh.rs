@@ -0,0 +1,212 @@
+use crate::{finals, NonRhoticFinal, SpellingInitial, Syllable, Tone};
+
+/// Displays a [`Syllable`] in Gwoyeu Romatzyh, where the tone is spelled out, such as `gwo`. Created by
+/// [`Syllable::to_gwoyeu_romatzyh`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GwoyeuRomatzyh {
+    syllable: Syllable,
+}
+
+const fn initial(initial: SpellingInitial) -> &'static str {
+    use SpellingInitial::{
+        ZeroAEO, B, C, CH, D, F, G, H, J, K, L, M, N, P, Q, R, S, SH, T, W, X, Y, Z, ZH,
+    };
+    match initial {
+        B => "b",
+        P => "p",
+        M => "m",
+        F => "f",
+        D => "d",
+        T => "t",
+        N => "n",
+        L => "l",
+        G => "g",
+        K => "k",
+        H => "h",
+        J | ZH => "j",
+        Q | CH => "ch",
+        X | SH => "sh",
+        R => "r",
+        Z => "tz",
+        C => "ts",
+        S => "s",
+        Y | W | ZeroAEO => "",
+    }
+}
+
+/// The final in its basic form, split into the medial, the main vowel and the ending
+const fn parts(fin: NonRhoticFinal) -> (&'static str, &'static str, &'static str) {
+    use NonRhoticFinal as F;
+    match fin {
+        F::A => ("", "a", ""),
+        F::O => ("", "o", ""),
+        F::E => ("", "e", ""),
+        F::Ê => ("", "è", ""),
+        F::Ai => ("", "a", "i"),
+        F::Ei => ("", "e", "i"),
+        F::Ao => ("", "a", "u"),
+        F::Ou => ("", "o", "u"),
+        F::An => ("", "a", "n"),
+        F::En => ("", "e", "n"),
+        F::Ang => ("", "a", "ng"),
+        F::Eng => ("", "e", "ng"),
+        F::Ong => ("", "o", "ng"),
+        F::I => ("i", "", ""),
+        F::Ia => ("i", "a", ""),
+        F::Io => ("i", "o", ""),
+        F::Ie => ("i", "e", ""),
+        F::Iao => ("i", "a", "u"),
+        F::Iu => ("i", "o", "u"),
+        F::Ian => ("i", "a", "n"),
+        F::In => ("i", "", "n"),
+        F::Iang => ("i", "a", "ng"),
+        F::Ing => ("i", "", "ng"),
+        F::Iong => ("i", "o", "ng"),
+        F::U => ("u", "", ""),
+        F::Ua => ("u", "a", ""),
+        F::Uo => ("u", "o", ""),
+        F::Uai => ("u", "a", "i"),
+        F::Ui => ("u", "e", "i"),
+        F::Uan => ("u", "a", "n"),
+        F::Un => ("u", "e", "n"),
+        F::Uang => ("u", "a", "ng"),
+        F::Ü => ("iu", "", ""),
+        F::Ue | F::Üe => ("iu", "e", ""),
+        F::Üan => ("iu", "a", "n"),
+        F::Ün => ("iu", "", "n"),
+        F::M => ("", "m", ""),
+        F::N => ("", "n", ""),
+    }
+}
+
+/// The medial as written in the second tone, and in the fourth tone without an initial
+fn glide(medial: &str, vowel: &str) -> String {
+    match (medial, vowel) {
+        ("i", "") => "yi".to_owned(),
+        ("u", "") => "wu".to_owned(),
+        ("i", _) => "y".to_owned(),
+        ("u", _) => "w".to_owned(),
+        ("iu", _) => "yu".to_owned(),
+        _ => String::new(),
+    }
+}
+
+impl GwoyeuRomatzyh {
+    /// The final spelled out in the tone; `zero` if the syllable has no initial
+    fn rhyme(medial: &str, vowel: &str, ending: &str, tone: Tone, zero: bool) -> String {
+        match tone {
+            Tone::First | Tone::Fifth => format!("{medial}{vowel}{ending}"),
+            Tone::Second => {
+                if medial.is_empty() && matches!(ending, "i" | "u") {
+                    format!("{vowel}{ending}r")
+                } else if medial.is_empty() {
+                    format!("{vowel}r{ending}")
+                } else if medial == "i" && vowel.is_empty() && !ending.is_empty() {
+                    // `in` and `ing` become `yn` and `yng`
+                    format!("y{ending}")
+                } else {
+                    format!("{}{vowel}{ending}", glide(medial, vowel))
+                }
+            }
+            Tone::Third => {
+                let ans = match (medial, vowel, ending) {
+                    ("", "a", "i") => "ae".to_owned(),
+                    ("", "a", "u") => "ao".to_owned(),
+                    ("", _, _) => format!("{vowel}{vowel}{ending}"),
+                    ("iu", "", _) => format!("eu{ending}"),
+                    (_, "", _) => format!("{medial}{medial}{ending}"),
+                    ("i", "e", _) | ("u", "o", _) => format!("{medial}{vowel}{vowel}{ending}"),
+                    ("i", _, _) => format!("e{vowel}{ending}"),
+                    ("u", _, _) => format!("o{vowel}{ending}"),
+                    _ => format!("eu{vowel}{ending}"),
+                };
+                if !zero || medial.is_empty() {
+                    return ans;
+                }
+                let glide = if medial == "u" { 'w' } else { 'y' };
+                let mut chars = ans.chars();
+                match (chars.next(), chars.next()) {
+                    // `iee` and `uoo` become `yee` and `woo`
+                    (Some(c @ ('i' | 'u')), Some(d)) if c != d => format!("{glide}{}", &ans[1..]),
+                    _ => format!("{glide}{ans}"),
+                }
+            }
+            Tone::Fourth => {
+                let ending = match ending {
+                    "i" => "y",
+                    "u" => "w",
+                    "n" => "nn",
+                    "ng" => "nq",
+                    "l" => "ll",
+                    _ => "h",
+                };
+                if zero && !medial.is_empty() {
+                    format!("{}{vowel}{ending}", glide(medial, vowel))
+                } else {
+                    format!("{medial}{vowel}{ending}")
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for GwoyeuRomatzyh {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use SpellingInitial as I;
+        let Syllable {
+            initial: i,
+            fin,
+            tone,
+            erhua,
+            ..
+        } = self.syllable;
+        let underlying = finals::underlying(i, fin);
+        if tone == Tone::Fifth {
+            write!(f, "·")?;
+        }
+        if matches!(underlying, NonRhoticFinal::M | NonRhoticFinal::N) {
+            return write!(f, "{}", parts(underlying).1);
+        }
+        // `è` would read as a fourth tone
+        if tone == Tone::Fifth && underlying == NonRhoticFinal::Ê {
+            return write!(f, "ê");
+        }
+
+        let (medial, vowel, ending) = match (i, underlying) {
+            (I::ZH | I::CH | I::SH | I::R | I::Z | I::C | I::S, NonRhoticFinal::I) => ("", "y", ""),
+            (I::ZeroAEO, NonRhoticFinal::E) if erhua => ("", "e", "l"),
+            // `weng` is `ueng`
+            (I::W, _) if fin == NonRhoticFinal::Eng => ("u", "e", "ng"),
+            _ => parts(underlying),
+        };
+        let erhua = if erhua && ending != "l" { "l" } else { "" };
+        let zero = matches!(i, I::Y | I::W | I::ZeroAEO);
+
+        // the sonorant initials mark the first tone with an `h`, and take the basic form in the second tone
+        let sonorant = matches!(i, I::M | I::N | I::L | I::R);
+        let (h, tone) = match tone {
+            Tone::First if sonorant => ("h", Tone::First),
+            Tone::Second if sonorant => ("", Tone::First),
+            _ => ("", tone),
+        };
+        let rhyme = Self::rhyme(medial, vowel, ending, tone, zero);
+        write!(f, "{}{h}{rhyme}{erhua}", initial(i))
+    }
+}
+
+impl Syllable {
+    /// Renders the syllable in Gwoyeu Romatzyh. The light tone takes the basic form, preceded by `·`,
+    /// and the erhua is marked by a trailing `l` without the changes to the final.
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let gr = PinyinParser::new()
+    ///     .try_parse_syllables("guō guó guǒ guò mā má mǎ mà de")
+    ///     .map(|s| s.unwrap().to_gwoyeu_romatzyh().to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(gr, vec!["guo", "gwo", "guoo", "guoh", "mha", "ma", "maa", "mah", "·de"]);
+    /// ```
+    #[must_use]
+    pub const fn to_gwoyeu_romatzyh(self) -> GwoyeuRomatzyh {
+        GwoyeuRomatzyh { syllable: self }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{finals, NonRhoticFinal, SpellingInitial, Syllable};
+
+/// Displays a [`Syllable`] in Hangul, following the Korean rules for transcribing Mandarin, such as `징`.
+/// Created by [`Syllable::to_hangul`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Hangul {
+    syllable: Syllable,
+}
+
+const FIRST_BLOCK: u32 = 0xAC00;
+const VOWELS: u32 = 21;
+const TAILS: u32 = 28;
+
+/// The index of `ㄹ` among the tails
+const TAIL_L: u32 = 8;
+
+/// The index of the leading consonant that `initial` is written with; `None` for the silent `ㅇ`
+const fn lead(initial: SpellingInitial) -> Option<u32> {
+    use SpellingInitial::{
+        ZeroAEO, B, C, CH, D, F, G, H, J, K, L, M, N, P, Q, R, S, SH, T, W, X, Y, Z, ZH,
+    };
+    Some(match initial {
+        G => 0,
+        N => 2,
+        D => 3,
+        L | R => 5,
+        M => 6,
+        B => 7,
+        X | SH => 9,
+        S => 10,
+        J | ZH => 12,
+        Z => 13,
+        Q | CH | C => 14,
+        K => 15,
+        T => 16,
+        P | F => 17,
+        H => 18,
+        Y | W | ZeroAEO => return None,
+    })
+}
+
+/// The final as written without an initial, and after one
+const fn rhyme(fin: NonRhoticFinal) -> (&'static str, &'static str) {
+    use NonRhoticFinal as F;
+    match fin {
+        F::A => ("아", "아"),
+        F::O => ("오", "오"),
+        F::E => ("어", "어"),
+        F::Ê => ("에", "에"),
+        F::Ai => ("아이", "아이"),
+        F::Ei => ("에이", "에이"),
+        F::Ao => ("아오", "아오"),
+        F::Ou => ("어우", "어우"),
+        F::An => ("안", "안"),
+        F::En => ("언", "언"),
+        F::Ang => ("앙", "앙"),
+        F::Eng => ("엉", "엉"),
+        F::Ong => ("웡", "웅"),
+        F::I => ("이", "이"),
+        F::Ia => ("야", "야"),
+        F::Io => ("요", "요"),
+        F::Ie => ("예", "예"),
+        F::Iao => ("야오", "야오"),
+        F::Iu => ("유", "유"),
+        F::Ian => ("옌", "옌"),
+        F::In => ("인", "인"),
+        F::Iang => ("양", "양"),
+        F::Ing => ("잉", "잉"),
+        F::Iong => ("융", "융"),
+        F::U => ("우", "우"),
+        F::Ua => ("와", "와"),
+        F::Uo => ("워", "워"),
+        F::Uai => ("와이", "와이"),
+        F::Ui => ("웨이", "우이"),
+        F::Uan => ("완", "완"),
+        F::Un => ("원", "운"),
+        F::Uang => ("왕", "왕"),
+        F::Ü => ("위", "위"),
+        F::Ue | F::Üe => ("웨", "웨"),
+        F::Üan => ("위안", "위안"),
+        F::Ün => ("윈", "윈"),
+        F::M => ("음", "음"),
+        F::N => ("은", "은"),
+    }
+}
+
+/// Splits a Hangul block into the indices of its leading consonant, vowel and tail
+fn decompose(c: char) -> (u32, u32, u32) {
+    let i = u32::from(c) - FIRST_BLOCK;
+    (i / (VOWELS * TAILS), i / TAILS % VOWELS, i % TAILS)
+}
+
+fn compose(lead: u32, vowel: u32, tail: u32) -> char {
+    char::from_u32(FIRST_BLOCK + (lead * VOWELS + vowel) * TAILS + tail).unwrap_or('\u{fffd}')
+}
+
+impl std::fmt::Display for Hangul {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use NonRhoticFinal as F;
+        use SpellingInitial as I;
+        let Syllable {
+            initial: i,
+            fin,
+            erhua,
+            ..
+        } = self.syllable;
+        let underlying = finals::underlying(i, fin);
+        let rhyme = match (i, underlying) {
+            // the empty rhymes
+            (I::Z | I::C | I::S | I::ZH | I::CH | I::SH | I::R, F::I) => "으",
+            (I::ZeroAEO, F::E) if erhua => "얼",
+            (I::Y | I::W | I::ZeroAEO, _) => rhyme(underlying).0,
+            _ => rhyme(underlying).1,
+        };
+        let mut blocks = rhyme.chars().map(decompose).collect::<Vec<_>>();
+        if let (Some(lead), Some(first)) = (lead(i), blocks.first_mut()) {
+            first.0 = lead;
+            // after `ㅈ`, `ㅉ` and `ㅊ`, `ㅑ`, `ㅖ`, `ㅛ` and `ㅠ` are written as `ㅏ`, `ㅔ`, `ㅗ` and `ㅜ`
+            if matches!(lead, 12..=14) {
+                first.1 = match first.1 {
+                    2 => 0,
+                    7 => 5,
+                    12 => 8,
+                    17 => 13,
+                    v => v,
+                };
+            }
+        }
+        let mut erhua = erhua && rhyme != "얼";
+        if let Some(last) = blocks.last_mut().filter(|last| erhua && last.2 == 0) {
+            last.2 = TAIL_L;
+            erhua = false;
+        }
+        for (lead, vowel, tail) in blocks {
+            write!(f, "{}", compose(lead, vowel, tail))?;
+        }
+        if erhua {
+            write!(f, "얼")?;
+        }
+        Ok(())
+    }
+}
+
+impl Syllable {
+    /// Renders the syllable in Hangul, following the Korean rules for transcribing Mandarin. The tone is dropped.
+    /// The erhua is written as a final `ㄹ`, or as `얼` after a syllable that already ends in a consonant.
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let hangul = PinyinParser::new()
+    ///     .try_parse_syllables("Běijīng Shànghǎi Zhōngguó")
+    ///     .map(|s| s.unwrap().to_hangul().to_string())
+    ///     .collect::<String>();
+    /// assert_eq!(hangul, "베이징상하이중궈");
+    /// ```
+    #[must_use]
+    pub const fn to_hangul(self) -> Hangul {
+        Hangul { syllable: self }
+    }
+}
@@ -0,0 +1,388 @@
+use crate::{NonRhoticFinal, PinyinParser, Span, SpellingInitial, Syllable, Token, Tone};
+
+/// Whether `initial` followed by `fin`, as spelled in pinyin, makes up a syllable of Standard Mandarin.
+///
+/// Syllables such as `bue`, `fi`, `gü`, `zhin` or `jo` are spelled out correctly but do not exist.
+/// The syllables are checked as spelled, so `nüe` and `ju` are valid while `nue` and `jü` are not.
+/// Rare and interjectional syllables, such as `dia`, `fiao` or `hm`, are left out.
+/// ```
+/// use pinyin_parser::{is_valid_syllable, NonRhoticFinal, SpellingInitial};
+/// assert!(is_valid_syllable(SpellingInitial::ZH, NonRhoticFinal::Ong));
+/// assert!(is_valid_syllable(SpellingInitial::J, NonRhoticFinal::U));
+/// assert!(!is_valid_syllable(SpellingInitial::F, NonRhoticFinal::I));
+/// assert!(!is_valid_syllable(SpellingInitial::G, NonRhoticFinal::Ü));
+/// ```
+#[must_use]
+#[allow(clippy::too_many_lines)]
+pub const fn is_valid_syllable(initial: SpellingInitial, fin: NonRhoticFinal) -> bool {
+    use NonRhoticFinal::{
+        Ai, An, Ang, Ao, Ei, En, Eng, Ia, Ian, Iang, Iao, Ie, In, Ing, Iong, Iu, Ong, Ou, Ua, Uai,
+        Uan, Uang, Ue, Ui, Un, Uo, Üe, A, E, I, M, N, O, U, Ê, Ü,
+    };
+    use SpellingInitial as S;
+    match initial {
+        S::ZeroAEO => matches!(
+            fin,
+            A | O | E | Ê | Ai | Ei | Ao | Ou | An | En | Ang | Eng | M | N
+        ),
+        S::Y => matches!(
+            fin,
+            I | A | O | E | Ai | Ao | Ou | An | In | Ang | Ing | Ong | U | Ue | Uan | Un
+        ),
+        S::W => matches!(fin, U | A | O | Ai | Ei | An | En | Ang | Eng),
+        S::B => matches!(
+            fin,
+            A | O | Ai | Ei | Ao | An | En | Ang | Eng | I | Ie | Iao | Ian | In | Ing | U
+        ),
+        S::P => matches!(
+            fin,
+            A | O | Ai | Ei | Ao | Ou | An | En | Ang | Eng | I | Ie | Iao | Ian | In | Ing | U
+        ),
+        S::M => matches!(
+            fin,
+            A | O
+                | E
+                | Ai
+                | Ei
+                | Ao
+                | Ou
+                | An
+                | En
+                | Ang
+                | Eng
+                | I
+                | Ie
+                | Iao
+                | Iu
+                | Ian
+                | In
+                | Ing
+                | U
+        ),
+        S::F => matches!(fin, A | O | Ei | Ou | An | En | Ang | Eng | U),
+        S::D => matches!(
+            fin,
+            A | E
+                | Ai
+                | Ei
+                | Ao
+                | Ou
+                | An
+                | En
+                | Ang
+                | Eng
+                | Ong
+                | I
+                | Ie
+                | Iao
+                | Iu
+                | Ian
+                | Ing
+                | U
+                | Uo
+                | Ui
+                | Uan
+                | Un
+        ),
+        S::T => matches!(
+            fin,
+            A | E
+                | Ai
+                | Ei
+                | Ao
+                | Ou
+                | An
+                | Ang
+                | Eng
+                | Ong
+                | I
+                | Ie
+                | Iao
+                | Ian
+                | Ing
+                | U
+                | Uo
+                | Ui
+                | Uan
+                | Un
+        ),
+        S::N => matches!(
+            fin,
+            A | E
+                | Ai
+                | Ei
+                | Ao
+                | Ou
+                | An
+                | En
+                | Ang
+                | Eng
+                | Ong
+                | I
+                | Ie
+                | Iao
+                | Iu
+                | Ian
+                | In
+                | Iang
+                | Ing
+                | U
+                | Uo
+                | Uan
+                | Ü
+                | Üe
+        ),
+        S::L => matches!(
+            fin,
+            A | O
+                | E
+                | Ai
+                | Ei
+                | Ao
+                | Ou
+                | An
+                | Ang
+                | Eng
+                | Ong
+                | I
+                | Ia
+                | Ie
+                | Iao
+                | Iu
+                | Ian
+                | In
+                | Iang
+                | Ing
+                | U
+                | Uo
+                | Uan
+                | Un
+                | Ü
+                | Üe
+        ),
+        S::G | S::K | S::H => matches!(
+            fin,
+            A | E
+                | Ai
+                | Ei
+                | Ao
+                | Ou
+                | An
+                | En
+                | Ang
+                | Eng
+                | Ong
+                | U
+                | Ua
+                | Uo
+                | Uai
+                | Ui
+                | Uan
+                | Un
+                | Uang
+        ),
+        S::J | S::Q | S::X => matches!(
+            fin,
+            I | Ia | Ie | Iao | Iu | Ian | In | Iang | Ing | Iong | U | Ue | Uan | Un
+        ),
+        S::ZH => matches!(
+            fin,
+            A | E
+                | Ai
+                | Ei
+                | Ao
+                | Ou
+                | An
+                | En
+                | Ang
+                | Eng
+                | Ong
+                | I
+                | U
+                | Ua
+                | Uo
+                | Uai
+                | Ui
+                | Uan
+                | Un
+                | Uang
+        ),
+        S::CH => matches!(
+            fin,
+            A | E
+                | Ai
+                | Ao
+                | Ou
+                | An
+                | En
+                | Ang
+                | Eng
+                | Ong
+                | I
+                | U
+                | Ua
+                | Uo
+                | Uai
+                | Ui
+                | Uan
+                | Un
+                | Uang
+        ),
+        S::SH => matches!(
+            fin,
+            A | E
+                | Ai
+                | Ei
+                | Ao
+                | Ou
+                | An
+                | En
+                | Ang
+                | Eng
+                | I
+                | U
+                | Ua
+                | Uo
+                | Uai
+                | Ui
+                | Uan
+                | Un
+                | Uang
+        ),
+        S::R => matches!(
+            fin,
+            E | Ao | Ou | An | En | Ang | Eng | Ong | I | U | Uo | Ui | Uan | Un
+        ),
+        S::Z => matches!(
+            fin,
+            A | E | Ai | Ei | Ao | Ou | An | En | Ang | Eng | Ong | I | U | Uo | Ui | Uan | Un
+        ),
+        S::C | S::S => matches!(
+            fin,
+            A | E | Ai | Ao | Ou | An | En | Ang | Eng | Ong | I | U | Uo | Ui | Uan | Un
+        ),
+    }
+}
+
+const INITIALS: [SpellingInitial; 24] = {
+    use SpellingInitial::{B, C, CH, D, F, G, H, J, K, L, M, N, P, Q, R, S, SH, T, W, X, Y, Z, ZH};
+    [
+        SpellingInitial::ZeroAEO,
+        B,
+        P,
+        M,
+        F,
+        D,
+        T,
+        N,
+        L,
+        G,
+        K,
+        H,
+        J,
+        Q,
+        X,
+        ZH,
+        CH,
+        SH,
+        R,
+        Z,
+        C,
+        S,
+        Y,
+        W,
+    ]
+};
+
+pub const FINALS: [NonRhoticFinal; 39] = {
+    use NonRhoticFinal::{
+        Ai, An, Ang, Ao, Ei, En, Eng, Ia, Ian, Iang, Iao, Ie, In, Ing, Io, Iong, Iu, Ong, Ou, Ua,
+        Uai, Uan, Uang, Ue, Ui, Un, Uo, Üan, Üe, Ün, A, E, I, M, N, O, U, Ê, Ü,
+    };
+    [
+        A, O, E, Ê, Ai, Ei, Ao, Ou, An, En, Ang, Eng, Ong, I, Ia, Io, Ie, Iao, Iu, Ian, In, Iang,
+        Ing, Iong, U, Ua, Uo, Uai, Ui, Uan, Un, Uang, Ue, Ü, Üe, Üan, Ün, M, N,
+    ]
+};
+
+const TONES: [Tone; 5] = [
+    Tone::First,
+    Tone::Second,
+    Tone::Third,
+    Tone::Fourth,
+    Tone::Fifth,
+];
+
+/// Every syllable of Standard Mandarin in every tone, in lowercase and without erhua except for `ér`.
+/// The syllabic `m` and `n` come without the light tone.
+///
+/// The syllables are those accepted by [`is_valid_syllable`], so each of them parses back to itself in strict mode.
+/// They come ordered by initial, then by final, then by tone.
+/// ```
+/// use pinyin_parser::syllables;
+/// let all = syllables().map(|s| s.to_string()).collect::<Vec<_>>();
+/// assert_eq!(all[..6], ["ā", "á", "ǎ", "à", "a", "ō"]);
+/// assert!(all.contains(&"nǚ".to_string()));
+/// assert!(all.contains(&"ér".to_string()));
+/// assert!(!all.contains(&"fi".to_string()));
+/// ```
+pub fn syllables() -> impl Iterator<Item = Syllable> {
+    INITIALS
+        .into_iter()
+        .flat_map(|initial| FINALS.into_iter().map(move |fin| (initial, fin, false)))
+        .filter(|&(initial, fin, _)| is_valid_syllable(initial, fin))
+        .chain(std::iter::once((
+            SpellingInitial::ZeroAEO,
+            NonRhoticFinal::E,
+            true,
+        )))
+        .flat_map(|(initial, fin, erhua)| {
+            // a toneless `m` or `n` would be read as an initial
+            let tones = if matches!(fin, NonRhoticFinal::M | NonRhoticFinal::N) {
+                &TONES[..4]
+            } else {
+                &TONES[..]
+            };
+            tones.iter().map(move |&tone| Syllable {
+                initial,
+                fin,
+                tone,
+                erhua,
+                capitalized: false,
+                uppercase: 0,
+            })
+        })
+}
+
+impl PinyinParser {
+    /// Lists the syllables in `s` that are spelled out correctly but do not exist in Mandarin,
+    /// along with where they are; see [`is_valid_syllable`].
+    /// They are listed in the strict modes as well, and whatever is not spelled out correctly is skipped
+    /// as [`PinyinParser::parse_lossy`] skips it.
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let invalid = PinyinParser::new().invalid_syllables("fi bue zhōng");
+    /// assert_eq!(invalid.len(), 2);
+    /// assert_eq!(invalid[0].1.to_string(), "fi");
+    /// assert_eq!(invalid[1].0.bytes, 3..6);
+    /// ```
+    #[must_use]
+    pub fn invalid_syllables(self, s: &str) -> Vec<(Span, Syllable)> {
+        let mut it = self.try_parse(s);
+        let mut ans = vec![];
+        while let Some(item) = it.parse_spelling() {
+            match item {
+                Ok((span, Token::Syllable(syllable)))
+                    if !is_valid_syllable(syllable.initial, syllable.fin) =>
+                {
+                    ans.push((span, syllable));
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    it.skip_invalid(&e);
+                }
+            }
+        }
+        ans
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{finals, NonRhoticFinal, SpellingInitial, Syllable, Tone};
+
+/// How the tone is written after an IPA transcription
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum IpaToneStyle {
+    /// `ʈʂʊŋ˥`
+    #[default]
+    ChaoLetters,
+
+    /// `ʈʂʊŋ⁵⁵`
+    Digits,
+}
+
+/// Displays a [`Syllable`] as a broad IPA transcription, such as `ʈʂʊŋ˥`. Created by [`Syllable::to_ipa`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Ipa {
+    syllable: Syllable,
+    style: IpaToneStyle,
+}
+
+const fn initial(initial: SpellingInitial) -> &'static str {
+    use SpellingInitial::{
+        ZeroAEO, B, C, CH, D, F, G, H, J, K, L, M, N, P, Q, R, S, SH, T, W, X, Y, Z, ZH,
+    };
+    match initial {
+        B => "p",
+        P => "pʰ",
+        M => "m",
+        F => "f",
+        D => "t",
+        T => "tʰ",
+        N => "n",
+        L => "l",
+        G => "k",
+        K => "kʰ",
+        H => "x",
+        J => "tɕ",
+        Q => "tɕʰ",
+        X => "ɕ",
+        ZH => "ʈʂ",
+        CH => "ʈʂʰ",
+        SH => "ʂ",
+        R => "ʐ",
+        Z => "ts",
+        C => "tsʰ",
+        S => "s",
+        Y | W | ZeroAEO => "",
+    }
+}
+
+/// The final split into the medial, the nucleus and the coda
+const fn parts(fin: NonRhoticFinal) -> (&'static str, &'static str, &'static str) {
+    use NonRhoticFinal as F;
+    match fin {
+        F::A => ("", "a", ""),
+        F::O => ("", "o", ""),
+        F::E => ("", "ɤ", ""),
+        F::Ê => ("", "ɛ", ""),
+        F::Ai => ("", "a", "i"),
+        F::Ei => ("", "e", "i"),
+        F::Ao => ("", "ɑ", "u"),
+        F::Ou => ("", "o", "u"),
+        F::An => ("", "a", "n"),
+        F::En => ("", "ə", "n"),
+        F::Ang => ("", "ɑ", "ŋ"),
+        F::Eng => ("", "ə", "ŋ"),
+        F::Ong => ("", "ʊ", "ŋ"),
+        F::I => ("", "i", ""),
+        F::Ia => ("j", "a", ""),
+        F::Io => ("j", "o", ""),
+        F::Ie => ("j", "ɛ", ""),
+        F::Iao => ("j", "ɑ", "u"),
+        F::Iu => ("j", "o", "u"),
+        F::Ian => ("j", "ɛ", "n"),
+        F::In => ("", "i", "n"),
+        F::Iang => ("j", "ɑ", "ŋ"),
+        F::Ing => ("", "i", "ŋ"),
+        F::Iong => ("j", "ʊ", "ŋ"),
+        F::U => ("", "u", ""),
+        F::Ua => ("w", "a", ""),
+        F::Uo => ("w", "o", ""),
+        F::Uai => ("w", "a", "i"),
+        F::Ui => ("w", "e", "i"),
+        F::Uan => ("w", "a", "n"),
+        F::Un => ("w", "ə", "n"),
+        F::Uang => ("w", "ɑ", "ŋ"),
+        F::Ü => ("", "y", ""),
+        F::Ue | F::Üe => ("ɥ", "ɛ", ""),
+        F::Üan => ("ɥ", "ɛ", "n"),
+        F::Ün => ("", "y", "n"),
+        F::M => ("", "m̩", ""),
+        F::N => ("", "n̩", ""),
+    }
+}
+
+/// The rhotacised final, such as `waɻ` for `-uānr`
+fn rhotacised(medial: &str, nucleus: &str, coda: &str) -> String {
+    // the high vowels become glides before a schwa
+    let (medial, nucleus) = match nucleus {
+        "i" => ("j", "ə"),
+        "y" => ("ɥ", "ə"),
+        "ɹ̩" | "ɻ̩" => (medial, "ə"),
+        _ => (medial, nucleus),
+    };
+    match coda {
+        // the codas `-i` and `-n` are lost, and the front vowels are lowered or centralized
+        "i" | "n" => {
+            let nucleus = match nucleus {
+                "ɛ" => "a",
+                "e" => "ə",
+                _ => nucleus,
+            };
+            format!("{medial}{nucleus}ɻ")
+        }
+        // the coda `-ŋ` is lost, leaving the vowel nasalized
+        "ŋ" => format!("{medial}{nucleus}\u{303}ɻ"),
+        _ => format!("{medial}{nucleus}{coda}ɻ"),
+    }
+}
+
+impl std::fmt::Display for Ipa {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use NonRhoticFinal as F;
+        use SpellingInitial as I;
+        let Syllable {
+            initial: i,
+            fin,
+            tone,
+            erhua,
+            ..
+        } = self.syllable;
+        let underlying = finals::underlying(i, fin);
+        let (medial, nucleus, coda) = match (i, underlying) {
+            // the apical vowels
+            (I::Z | I::C | I::S, F::I) => ("", "ɹ̩", ""),
+            (I::ZH | I::CH | I::SH | I::R, F::I) => ("", "ɻ̩", ""),
+            (I::B | I::P | I::M | I::F, F::O) => ("w", "o", ""),
+            (I::W, F::Ong) => ("w", "ə", "ŋ"),
+            _ => parts(underlying),
+        };
+        let rhyme = if (i, underlying, erhua) == (I::ZeroAEO, F::E, true) {
+            "ɚ".to_owned()
+        } else if erhua {
+            rhotacised(medial, nucleus, coda)
+        } else {
+            format!("{medial}{nucleus}{coda}")
+        };
+        let tone = match (tone, self.style) {
+            (Tone::First, IpaToneStyle::ChaoLetters) => "˥",
+            (Tone::Second, IpaToneStyle::ChaoLetters) => "˧˥",
+            (Tone::Third, IpaToneStyle::ChaoLetters) => "˨˩˦",
+            (Tone::Fourth, IpaToneStyle::ChaoLetters) => "˥˩",
+            (Tone::First, IpaToneStyle::Digits) => "⁵⁵",
+            (Tone::Second, IpaToneStyle::Digits) => "³⁵",
+            (Tone::Third, IpaToneStyle::Digits) => "²¹⁴",
+            (Tone::Fourth, IpaToneStyle::Digits) => "⁵¹",
+            (Tone::Fifth, _) => "",
+        };
+        write!(f, "{}{rhyme}{tone}", initial(i))
+    }
+}
+
+impl Syllable {
+    /// Renders the syllable as a broad IPA transcription. The light tone is left unmarked.
+    /// ```
+    /// use pinyin_parser::{IpaToneStyle, PinyinParser};
+    /// let ipa = PinyinParser::new()
+    ///     .try_parse_syllables("zhōng sì xué huār")
+    ///     .map(|s| s.unwrap().to_ipa(IpaToneStyle::ChaoLetters).to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(ipa, vec!["ʈʂʊŋ˥", "sɹ̩˥˩", "ɕɥɛ˧˥", "xwaɻ˥"]);
+    /// ```
+    #[must_use]
+    pub const fn to_ipa(self, style: IpaToneStyle) -> Ipa {
+        Ipa {
+            syllable: self,
+            style,
+        }
+    }
+}
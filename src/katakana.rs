@@ -0,0 +1,184 @@
+use crate::{finals, NonRhoticFinal, SpellingInitial, Syllable};
+
+/// The way of writing Mandarin in katakana
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum KatakanaScheme {
+    /// Follows the sounds of Mandarin rather than the customary Japanese forms,
+    /// as in `ジョンゴ` for `Zhōngguó` rather than `チュウゴク`, or `ベイジン` rather than `ペキン`.
+    /// Each syllable is written with the row of its initial, `ゃ`-like small kana for the medials `i` and `ü`,
+    /// and `ぁ`-like ones for the medial `u`. `uo` after an initial is written as `o`, `e` as `u` followed by `ー`,
+    /// and `-n` and `-ng` both as `ン`. The erhua is written as `ル`, and the tone is dropped.
+    #[default]
+    Phonetic,
+}
+
+/// Displays a [`Syllable`] in katakana, such as `ジョン`. Created by [`Syllable::to_katakana`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Katakana {
+    syllable: Syllable,
+    scheme: KatakanaScheme,
+}
+
+/// The vowels, in the order of the columns of a row
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Vowel {
+    A,
+    I,
+    U,
+    E,
+    O,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Medial {
+    None,
+    I,
+    U,
+}
+
+/// The kana of `initial` followed by `a`, `i`, `u`, `e` and `o`
+const fn row(initial: SpellingInitial) -> [&'static str; 5] {
+    use SpellingInitial::{
+        ZeroAEO, B, C, CH, D, F, G, H, J, K, L, M, N, P, Q, R, S, SH, T, W, X, Y, Z, ZH,
+    };
+    match initial {
+        B => ["バ", "ビ", "ブ", "ベ", "ボ"],
+        P => ["パ", "ピ", "プ", "ペ", "ポ"],
+        M => ["マ", "ミ", "ム", "メ", "モ"],
+        F => ["ファ", "フィ", "フ", "フェ", "フォ"],
+        D => ["ダ", "ディ", "ドゥ", "デ", "ド"],
+        T => ["タ", "ティ", "トゥ", "テ", "ト"],
+        N => ["ナ", "ニ", "ヌ", "ネ", "ノ"],
+        L | R => ["ラ", "リ", "ル", "レ", "ロ"],
+        G => ["ガ", "ギ", "グ", "ゲ", "ゴ"],
+        K => ["カ", "キ", "ク", "ケ", "コ"],
+        H => ["ハ", "ヒ", "フ", "ヘ", "ホ"],
+        J | ZH => ["ジャ", "ジ", "ジュ", "ジェ", "ジョ"],
+        Q | CH => ["チャ", "チ", "チュ", "チェ", "チョ"],
+        X | SH => ["シャ", "シ", "シュ", "シェ", "ショ"],
+        Z => ["ザ", "ズ", "ズ", "ゼ", "ゾ"],
+        C => ["ツァ", "ツ", "ツ", "ツェ", "ツォ"],
+        S => ["サ", "ス", "ス", "セ", "ソ"],
+        Y | W | ZeroAEO => ["ア", "イ", "ウ", "エ", "オ"],
+    }
+}
+
+/// The final split into the medial, the vowel and what follows it
+const fn parts(fin: NonRhoticFinal) -> (Medial, Vowel, &'static str) {
+    use NonRhoticFinal as F;
+    match fin {
+        F::A => (Medial::None, Vowel::A, ""),
+        F::O => (Medial::None, Vowel::O, ""),
+        F::E => (Medial::None, Vowel::U, "ー"),
+        F::Ê => (Medial::None, Vowel::E, ""),
+        F::Ai => (Medial::None, Vowel::A, "イ"),
+        F::Ei => (Medial::None, Vowel::E, "イ"),
+        F::Ao => (Medial::None, Vowel::A, "オ"),
+        F::Ou => (Medial::None, Vowel::O, "ウ"),
+        F::An | F::Ang => (Medial::None, Vowel::A, "ン"),
+        F::En => (Medial::None, Vowel::E, "ン"),
+        F::Eng | F::Ong => (Medial::None, Vowel::O, "ン"),
+        F::I => (Medial::None, Vowel::I, ""),
+        F::Ia => (Medial::I, Vowel::A, ""),
+        F::Io => (Medial::I, Vowel::O, ""),
+        F::Ie => (Medial::I, Vowel::E, ""),
+        F::Iao => (Medial::I, Vowel::A, "オ"),
+        F::Iu => (Medial::I, Vowel::O, "ウ"),
+        F::Ian => (Medial::I, Vowel::E, "ン"),
+        F::In | F::Ing => (Medial::None, Vowel::I, "ン"),
+        F::Iang => (Medial::I, Vowel::A, "ン"),
+        F::Iong => (Medial::I, Vowel::O, "ン"),
+        // the syllabic nasals are written as `ム` and `ン` on their own
+        F::U | F::M | F::N => (Medial::None, Vowel::U, ""),
+        F::Ua => (Medial::U, Vowel::A, ""),
+        F::Uo => (Medial::U, Vowel::O, ""),
+        F::Uai => (Medial::U, Vowel::A, "イ"),
+        F::Ui => (Medial::U, Vowel::E, "イ"),
+        F::Uan | F::Uang => (Medial::U, Vowel::A, "ン"),
+        F::Un => (Medial::U, Vowel::E, "ン"),
+        F::Ü => (Medial::I, Vowel::U, "イ"),
+        F::Ue | F::Üe => (Medial::I, Vowel::U, "エ"),
+        F::Üan => (Medial::I, Vowel::U, "アン"),
+        F::Ün => (Medial::I, Vowel::U, "ン"),
+    }
+}
+
+/// The medial and the vowel written after the kana of `initial` followed by `i` or `u`,
+/// or on their own if there is no initial
+fn glide(initial: SpellingInitial, medial: Medial, vowel: Vowel) -> String {
+    use SpellingInitial as I;
+    let zero = matches!(initial, I::Y | I::W | I::ZeroAEO);
+    match (medial, vowel) {
+        (Medial::None, _) => row(initial)[vowel as usize].to_owned(),
+        (Medial::I, Vowel::A) if zero => "ヤ".to_owned(),
+        (Medial::I, Vowel::U) if zero => "ユ".to_owned(),
+        (Medial::I, Vowel::O) if zero => "ヨ".to_owned(),
+        (Medial::U, Vowel::A) if zero => "ワ".to_owned(),
+        (Medial::I | Medial::U, _) => {
+            let (base, small) = if medial == Medial::I {
+                (
+                    row(initial)[Vowel::I as usize],
+                    ["ャ", "ィ", "ュ", "ェ", "ョ"],
+                )
+            } else {
+                (
+                    row(initial)[Vowel::U as usize],
+                    ["ァ", "ィ", "ゥ", "ェ", "ォ"],
+                )
+            };
+            // `ジ`, `チ` and `シ` are palatal already, as in `ジャ`
+            match (medial, row(initial)[vowel as usize]) {
+                (Medial::I, kana) if matches!(initial, I::J | I::Q | I::X) => kana.to_owned(),
+                _ => format!("{base}{}", small[vowel as usize]),
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Katakana {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use NonRhoticFinal as F;
+        use SpellingInitial as I;
+        let KatakanaScheme::Phonetic = self.scheme;
+        let Syllable {
+            initial: i,
+            fin,
+            erhua,
+            ..
+        } = self.syllable;
+        let underlying = finals::underlying(i, fin);
+        let zero = matches!(i, I::Y | I::W | I::ZeroAEO);
+        let kana = match (i, underlying) {
+            (I::ZeroAEO, F::E) if erhua => "アル".to_owned(),
+            (_, F::M) => "ム".to_owned(),
+            (_, F::N) => "ン".to_owned(),
+            (_, F::Uo) if !zero => row(i)[Vowel::O as usize].to_owned(),
+            _ => {
+                let (medial, vowel, rest) = parts(underlying);
+                format!("{}{rest}", glide(i, medial, vowel))
+            }
+        };
+        let erhua = if erhua && kana != "アル" { "ル" } else { "" };
+        write!(f, "{kana}{erhua}")
+    }
+}
+
+impl Syllable {
+    /// Renders the syllable in katakana in the given scheme. The tone is dropped.
+    /// ```
+    /// use pinyin_parser::{KatakanaScheme, PinyinParser};
+    /// let katakana = PinyinParser::new()
+    ///     .try_parse_syllables("Zhōngguó Běijīng")
+    ///     .map(|s| s.unwrap().to_katakana(KatakanaScheme::Phonetic).to_string())
+    ///     .collect::<String>();
+    /// assert_eq!(katakana, "ジョンゴベイジン");
+    /// ```
+    #[must_use]
+    pub const fn to_katakana(self, scheme: KatakanaScheme) -> Katakana {
+        Katakana {
+            syllable: self,
+            scheme,
+        }
+    }
+}
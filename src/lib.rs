@@ -3,8 +3,70 @@
 
 #[cfg(test)]
 mod tests;
+use std::ops::Range;
 use unicode_segmentation::UnicodeSegmentation;
 
+mod error;
+pub use error::{ParseError, TableError};
+
+mod syllable;
+pub use syllable::{NeutralTone, Numbered, NumberedFormat, Syllable, UmlautSpelling};
+
+mod zhuyin;
+pub use zhuyin::Zhuyin;
+
+mod wade_giles;
+pub use wade_giles::{ToneNumberStyle, WadeGiles};
+
+mod wade_giles_token;
+pub use wade_giles_token::WadeGilesAmbiguity;
+
+mod gwoyeu_romatzyh;
+pub use gwoyeu_romatzyh::GwoyeuRomatzyh;
+
+mod yale;
+pub use yale::Yale;
+
+mod mps2;
+pub use mps2::MpsII;
+
+mod tongyong;
+pub use tongyong::Tongyong;
+
+mod ipa;
+pub use ipa::{Ipa, IpaToneStyle};
+
+mod palladius;
+pub use palladius::Palladius;
+
+mod hangul;
+pub use hangul::Hangul;
+
+mod katakana;
+pub use katakana::{Katakana, KatakanaScheme};
+
+mod romanization;
+pub use romanization::{Romanization, RomanizationTable};
+
+mod inventory;
+pub use inventory::{is_valid_syllable, syllables};
+
+mod underlying;
+pub use underlying::{Final, Initial, UnderlyingSyllable};
+
+mod segmentation;
+pub use segmentation::Segmentations;
+
+/// Where an emitted item or an error lies in the original string
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct Span {
+    /// Byte offsets, so that `&s[span.bytes]` gives back the original text
+    pub bytes: Range<usize>,
+
+    /// Indices of the grapheme clusters, as given by `unicode_segmentation`
+    pub graphemes: Range<usize>,
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
 pub enum Strictness {
     Strict,
@@ -20,12 +82,17 @@ impl Strictness {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
-#[allow(clippy::struct_excessive_bools)]
+#[allow(clippy::struct_excessive_bools, clippy::struct_field_names)]
 pub struct PinyinParser {
     p_strict: Strictness,
     p_preserve_punctuations: bool,
     p_preserve_spaces: bool,
     p_preserve_miscellaneous: bool,
+    p_preserve_capitalization: bool,
+    p_tone_numbers: bool,
+    p_zhuyin: bool,
+    p_wade_giles: bool,
+    p_tongyong: bool,
 }
 
 impl Default for PinyinParser {
@@ -42,6 +109,11 @@ impl PinyinParser {
             p_preserve_spaces: false,
             p_preserve_punctuations: false,
             p_preserve_miscellaneous: false,
+            p_preserve_capitalization: false,
+            p_tone_numbers: false,
+            p_zhuyin: false,
+            p_wade_giles: false,
+            p_tongyong: false,
         }
     }
 
@@ -103,6 +175,113 @@ impl PinyinParser {
         }
     }
 
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let parser = PinyinParser::new().preserve_capitalization(true);
+    /// assert_eq!(
+    ///     parser
+    ///         .parse("Nǐ qùguo BĚIJĪNG ma?")
+    ///         .into_iter()
+    ///         .collect::<Vec<_>>(),
+    ///     vec!["Nǐ", "qù", "guo", "BĚI", "JĪNG", "ma"]
+    /// )
+    /// ```
+    #[must_use]
+    pub const fn preserve_capitalization(self, b: bool) -> Self {
+        Self {
+            p_preserve_capitalization: b,
+            ..self
+        }
+    }
+
+    /// Accepts numbered pinyin, in which the tone is denoted by a digit (1 to 4, and 5 or 0 for the light tone)
+    /// following the syllable. The digit may also come right after the vowel that bears the tone.
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let parser = PinyinParser::new().accept_tone_numbers(true);
+    /// assert_eq!(
+    ///     parser
+    ///         .parse("Zhong1guo2 ren2 mi2ngtian1 qu4 ba5")
+    ///         .into_iter()
+    ///         .collect::<Vec<_>>(),
+    ///     vec!["zhōng", "guó", "rén", "míng", "tiān", "qù", "ba"]
+    /// )
+    /// ```
+    #[must_use]
+    pub const fn accept_tone_numbers(self, b: bool) -> Self {
+        Self {
+            p_tone_numbers: b,
+            ..self
+        }
+    }
+
+    /// Accepts Zhuyin (Bopomofo) alongside pinyin. The first tone may be either unmarked or marked with `ˉ`,
+    /// and a `ㄦ` not followed by a tone mark is taken as the erhua.
+    /// Zhuyin that corresponds to no syllable of pinyin is a [`ParseError::InvalidZhuyin`].
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let parser = PinyinParser::new().accept_zhuyin(true);
+    /// assert_eq!(
+    ///     parser
+    ///         .parse("ㄓㄨㄥㄨㄣˊ ㄧˋ ㄉㄧㄢˇㄦ ˙ㄅㄚ")
+    ///         .into_iter()
+    ///         .collect::<Vec<_>>(),
+    ///     vec!["zhōng", "wén", "yì", "diǎnr", "ba"]
+    /// )
+    /// ```
+    #[must_use]
+    pub const fn accept_zhuyin(self, b: bool) -> Self {
+        Self {
+            p_zhuyin: b,
+            ..self
+        }
+    }
+
+    /// Reads the letters as Wade–Giles instead of pinyin. Tone numbers, either plain or superscript, are accepted.
+    /// Where a syllable can be read in more than one way, as `chu` for either `zhu` or `ju` when the diaeresis is omitted,
+    /// the first of [`WadeGilesAmbiguity::readings`] is taken; see [`PinyinParser::wade_giles_ambiguities`].
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let parser = PinyinParser::new().accept_wade_giles(true);
+    /// assert_eq!(
+    ///     parser
+    ///         .parse("Mao² Tse²-tung¹, Ch’ing¹-tao³")
+    ///         .into_iter()
+    ///         .collect::<Vec<_>>(),
+    ///     vec!["máo", "zé", "dōng", "qīng", "dǎo"]
+    /// )
+    /// ```
+    #[must_use]
+    pub const fn accept_wade_giles(self, b: bool) -> Self {
+        Self {
+            p_wade_giles: b,
+            ..self
+        }
+    }
+
+    /// Reads the letters as Tongyong Pinyin instead of pinyin. A syllable without a tone mark is in the first tone,
+    /// and the light tone is marked with a ring, as in `de̊`.
+    /// With [`Strictness::Loose`], spellings often seen in place of Tongyong, such as `jhi`, `ju`, `liu` and `wen`,
+    /// are accepted as well; the strict modes take only the standard ones, such as `jhih`, `jyu`, `liou` and `wun`.
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let parser = PinyinParser::new().accept_tongyong(true);
+    /// assert_eq!(
+    ///     parser
+    ///         .parse("Jhongshan, Sinyì, cìhsyù")
+    ///         .into_iter()
+    ///         .collect::<Vec<_>>(),
+    ///     vec!["zhōng", "shān", "xīn", "yì", "cì", "xù"]
+    /// )
+    /// ```
+    #[must_use]
+    pub const fn accept_tongyong(self, b: bool) -> Self {
+        Self {
+            p_tongyong: b,
+            ..self
+        }
+    }
+
     /// ```
     /// use pinyin_parser::PinyinParser;
     /// let parser = PinyinParser::new()
@@ -117,17 +296,174 @@ impl PinyinParser {
     ///     vec!["nǐ", " ", "zuò", " ", "shén", "me", "?"]
     /// )
     /// ```
+    ///
+    /// # Panics
+    /// Panics when the string is not a valid pinyin. Use [`PinyinParser::try_parse`] to handle such cases gracefully.
     #[must_use]
     pub fn parse(self, s: &str) -> PinyinParserIter {
         PinyinParserIter {
+            inner: self.try_parse(s),
+        }
+    }
+
+    /// Same as [`PinyinParser::parse`], but yields an `Err` instead of panicking when the string is not a valid pinyin.
+    /// The iterator stops after yielding the first error.
+    /// ```
+    /// use pinyin_parser::{ParseError, PinyinParser, Strictness};
+    /// let parser = PinyinParser::new().with_strictness(Strictness::Strict);
+    /// let mut it = parser.try_parse("Zhōngguó Yīng'guó");
+    /// assert_eq!(it.next(), Some(Ok("zhōng".to_owned())));
+    /// assert_eq!(it.next(), Some(Ok("guó".to_owned())));
+    /// assert_eq!(it.next(), Some(Ok("yīng".to_owned())));
+    /// assert!(matches!(
+    ///     it.next(),
+    ///     Some(Err(ParseError::ApostropheNotFollowedByAEO { .. }))
+    /// ));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    #[must_use]
+    pub fn try_parse(self, s: &str) -> PinyinParserTryIter {
+        let mut it = self.tokenize(s);
+        if self.p_zhuyin {
+            it.apply_zhuyin(self.p_strict);
+        }
+        if self.p_wade_giles {
+            it.apply_wade_giles(self.p_strict);
+        }
+        if self.p_tongyong {
+            it.apply_tongyong(self.p_strict);
+        }
+        if self.p_tone_numbers || self.p_wade_giles {
+            it.apply_tone_numbers();
+        }
+        PinyinParserTryIter {
             configs: self,
-            it: VecAndIndex {
-                vec: UnicodeSegmentation::graphemes(s, true)
-                    .map(|c| pinyin_token::to_token(c, self.p_strict))
-                    .collect::<Vec<_>>(),
-                next_pos: 0,
-            },
+            it,
             state: ParserState::BeforeWordInitial,
+            start: 0,
+            failed: false,
+            source: s.to_owned(),
+        }
+    }
+
+    fn tokenize(self, s: &str) -> VecAndIndex<Result<pinyin_token::PinyinToken, ParseError>> {
+        let (offsets, graphemes): (Vec<_>, Vec<_>) =
+            UnicodeSegmentation::grapheme_indices(s, true).unzip();
+        let offsets = offsets
+            .into_iter()
+            .chain(std::iter::once(s.len()))
+            .collect::<Vec<_>>();
+        let vec = graphemes
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let span = Span {
+                    bytes: offsets[i]..offsets[i + 1],
+                    graphemes: i..i + 1,
+                };
+                pinyin_token::to_token(c, &span, self.p_strict)
+            })
+            .collect::<Vec<_>>();
+        VecAndIndex {
+            vec,
+            offsets,
+            grapheme_offsets: (0..=graphemes.len()).collect(),
+            next_pos: 0,
+        }
+    }
+
+    /// Same as [`PinyinParser::try_parse`], but yields structured [`Syllable`]s instead of strings.
+    /// Spaces, punctuations and other characters are skipped, whatever the configuration is.
+    #[must_use]
+    pub fn try_parse_syllables(self, s: &str) -> PinyinParserSyllableIter {
+        PinyinParserSyllableIter {
+            inner: self.try_parse(s),
+        }
+    }
+
+    /// Same as [`PinyinParser::try_parse`], but yields every piece of the input as a [`Token`],
+    /// so that the syllables can be told apart from spaces, punctuations and other characters.
+    /// Everything is yielded, whatever the configuration is.
+    /// ```
+    /// use pinyin_parser::{PinyinParser, Token};
+    /// let tokens = PinyinParser::new()
+    ///     .try_parse_tokens("你Nǐ hǎo?")
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    /// assert_eq!(tokens[0], Token::Other("你".to_owned()));
+    /// assert!(matches!(&tokens[1], Token::Syllable(s) if s.to_string() == "nǐ"));
+    /// assert_eq!(tokens[2], Token::Space(" ".to_owned()));
+    /// assert!(matches!(&tokens[3], Token::Syllable(s) if s.to_string() == "hǎo"));
+    /// assert_eq!(tokens[4], Token::Punctuation("?".to_owned()));
+    /// ```
+    #[must_use]
+    pub fn try_parse_tokens(self, s: &str) -> PinyinParserTokenIter {
+        PinyinParserTokenIter {
+            inner: self.try_parse(s),
+        }
+    }
+
+    /// Same as [`PinyinParser::try_parse`], but does not stop at an error.
+    /// The offending region, up to the next space, punctuation, apostrophe or capital letter, is skipped
+    /// and reported as [`LossyItem::Invalid`], and the parsing resumes from there.
+    /// ```
+    /// use pinyin_parser::{LossyItem, PinyinParser};
+    /// let items = PinyinParser::new()
+    ///     .parse_lossy("nǐ hǎo bx Zhōngguó")
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(items[0], LossyItem::Parsed("nǐ".to_owned()));
+    /// assert_eq!(items[1], LossyItem::Parsed("hǎo".to_owned()));
+    /// assert!(matches!(&items[2], LossyItem::Invalid { text, .. } if text == "bx"));
+    /// assert_eq!(items[3], LossyItem::Parsed("zhōng".to_owned()));
+    /// assert_eq!(items[4], LossyItem::Parsed("guó".to_owned()));
+    /// ```
+    #[must_use]
+    pub fn parse_lossy(self, s: &str) -> PinyinParserLossyIter {
+        PinyinParserLossyIter {
+            inner: self.try_parse(s),
+        }
+    }
+
+    /// Same as [`PinyinParser::try_parse`], but also tells where each item comes from, in byte offsets.
+    /// Every [`ParseError`] carries its own [`Span`], available through [`ParseError::span`].
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let s = "Nǐ hǎo";
+    /// let spanned = PinyinParser::new()
+    ///     .parse_with_spans(s)
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     spanned,
+    ///     vec![(0..3, "nǐ".to_owned()), (4..8, "hǎo".to_owned())]
+    /// );
+    /// assert_eq!(&s[spanned[1].0.clone()], "hǎo");
+    /// ```
+    #[must_use]
+    pub fn parse_with_spans(self, s: &str) -> PinyinParserSpannedIter {
+        PinyinParserSpannedIter {
+            inner: self.try_parse(s),
+        }
+    }
+
+    /// How the string iterators should spell the token
+    fn render(self, token: &Token) -> String {
+        match token {
+            Token::Syllable(syllable) if self.p_preserve_capitalization => {
+                syllable.to_string_preserving_capitalization()
+            }
+            _ => token.to_string(),
+        }
+    }
+
+    /// Whether the string iterators should yield the token
+    const fn preserves(self, token: &Token) -> bool {
+        match token {
+            Token::Syllable(_) => true,
+            Token::Space(_) => self.p_preserve_spaces,
+            Token::Punctuation(_) => self.p_preserve_punctuations,
+            Token::Other(_) => self.p_preserve_miscellaneous,
+            Token::Apostrophe | Token::LightToneMarker => false,
         }
     }
 
@@ -144,7 +480,7 @@ impl PinyinParser {
     ///     vec!["jīn", "tiān"]
     /// );
     /// ```
-
+    ///
     /// ```should_panic
     /// use pinyin_parser::PinyinParser;
     /// assert_eq!(
@@ -154,7 +490,7 @@ impl PinyinParser {
     ///     vec!["zǒng", "shì"]
     /// );
     /// ```
-
+    ///
     /// ```should_panic
     /// use pinyin_parser::PinyinParser;
     /// assert_eq!(
@@ -165,7 +501,7 @@ impl PinyinParser {
     ///     vec!["yīng", "guó"]
     /// );
     /// ```
-
+    ///
     /// This parser supports the use of `ẑ`, `ĉ`, `ŝ` and `ŋ`, though I have never seen anyone use it.
     /// ```
     /// use pinyin_parser::PinyinParser;
@@ -174,7 +510,6 @@ impl PinyinParser {
     ///     vec!["zhāng"]
     /// )
     /// ```
-
     #[must_use]
     pub fn strict(s: &str) -> PinyinParserIter {
         Self::new().with_strictness(Strictness::Strict).parse(s)
@@ -191,7 +526,7 @@ impl PinyinParser {
     ///     vec!["mián", "ǎo"]
     /// );
     /// ```
-
+    ///
     /// ```
     /// use pinyin_parser::PinyinParser;
     /// assert_eq!(
@@ -213,13 +548,129 @@ mod pinyin_token;
 
 struct VecAndIndex<T> {
     vec: std::vec::Vec<T>,
+
+    /// `offsets[i]` is the byte offset at which `vec[i]` begins; has one extra element at the end
+    offsets: std::vec::Vec<usize>,
+
+    /// Same as `offsets`, but counted in grapheme clusters.
+    /// Differs from the index into `vec` once a token is removed, as is done to tone numbers.
+    grapheme_offsets: std::vec::Vec<usize>,
     next_pos: usize,
 }
 
 pub struct PinyinParserIter {
+    inner: PinyinParserTryIter,
+}
+
+pub struct PinyinParserTryIter {
     configs: PinyinParser,
-    it: VecAndIndex<pinyin_token::PinyinToken>,
+    it: VecAndIndex<Result<pinyin_token::PinyinToken, ParseError>>,
     state: ParserState,
+
+    /// Where the item currently being parsed began
+    start: usize,
+    failed: bool,
+    source: String,
+}
+
+pub struct PinyinParserSpannedIter {
+    inner: PinyinParserTryIter,
+}
+
+/// A piece of the input, as classified by the parser
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Token {
+    Syllable(Syllable),
+    Space(String),
+    Punctuation(String),
+
+    /// Anything that is not pinyin, such as Hanzi
+    Other(String),
+
+    /// An apostrophe separating two syllables, as in `mián'ǎo`
+    Apostrophe,
+
+    /// `·`, which marks the syllable that follows as having the light tone
+    LightToneMarker,
+}
+
+impl Token {
+    /// `ḿ`, `ń` and the like. Passed through as is if the diacritics do not denote a tone.
+    fn syllabic_nasal(
+        alph: &pinyin_token::AlphabetWithDiacritics,
+        fin: NonRhoticFinal,
+        strictness: Strictness,
+    ) -> Self {
+        finals::tone_of_lone_diacritic(&alph.diacritics, strictness).map_or_else(
+            || Self::Other(alph.to_str(strictness)),
+            |tone| {
+                Self::Syllable(Syllable {
+                    initial: SpellingInitial::ZeroAEO,
+                    fin,
+                    tone,
+                    erhua: false,
+                    capitalized: alph.capitalized,
+                    uppercase: u16::from(alph.capitalized),
+                })
+            },
+        )
+    }
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Syllable(syllable) => write!(f, "{syllable}"),
+            Self::Space(s) | Self::Punctuation(s) | Self::Other(s) => write!(f, "{s}"),
+            Self::Apostrophe => write!(f, "'"),
+            Self::LightToneMarker => write!(f, "·"),
+        }
+    }
+}
+
+pub struct PinyinParserTokenIter {
+    inner: PinyinParserTryIter,
+}
+
+impl Iterator for PinyinParserTokenIter {
+    type Item = Result<Token, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next_item().map(|r| r.map(|(_, token)| token))
+    }
+}
+
+pub struct PinyinParserSyllableIter {
+    inner: PinyinParserTryIter,
+}
+
+impl Iterator for PinyinParserSyllableIter {
+    type Item = Result<Syllable, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.inner.next_item()? {
+                Ok((_, Token::Syllable(syllable))) => return Some(Ok(syllable)),
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+pub struct PinyinParserLossyIter {
+    inner: PinyinParserTryIter,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LossyItem {
+    Parsed(String),
+
+    /// A region that could not be parsed, along with the error that was encountered first in it
+    Invalid {
+        text: String,
+        reason: ParseError,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -230,6 +681,45 @@ enum ParserState {
     AfterSyllablePossiblyConsumingApostrophe,
 }
 
+impl ParserState {
+    /// The initial parsed so far, for error messages
+    fn spelled_initial(self) -> String {
+        match self {
+            Self::InitialParsed(initial) => initial.to_string(),
+            Self::ZCSParsed(ZCS::Z) => "z".to_owned(),
+            Self::ZCSParsed(ZCS::C) => "c".to_owned(),
+            Self::ZCSParsed(ZCS::S) => "s".to_owned(),
+            Self::BeforeWordInitial | Self::AfterSyllablePossiblyConsumingApostrophe => {
+                String::new()
+            }
+        }
+    }
+}
+
+impl VecAndIndex<Result<pinyin_token::PinyinToken, ParseError>> {
+    fn a_e_o_follows(&self, n: usize) -> bool {
+        match self.peek(n) {
+            Some(Ok(pinyin_token::PinyinToken::Alph(a))) => matches!(
+                a.alphabet,
+                pinyin_token::Alphabet::A | pinyin_token::Alphabet::E | pinyin_token::Alphabet::O
+            ),
+            _ => false,
+        }
+    }
+
+    /// Looks for a malformed character before the word ends
+    fn first_error_in_word(&self) -> Option<&ParseError> {
+        for token in &self.vec[self.next_pos.min(self.vec.len())..] {
+            match token {
+                Ok(pinyin_token::PinyinToken::Alph(_)) => {}
+                Ok(_) => return None,
+                Err(e) => return Some(e),
+            }
+        }
+        None
+    }
+}
+
 impl<T> VecAndIndex<T> {
     fn next(&mut self) -> Option<&T> {
         let ans = self.vec.get(self.next_pos);
@@ -246,35 +736,162 @@ impl<T> VecAndIndex<T> {
         self.next_pos -= n;
     }
 
-    fn advance(&mut self, n: usize) {
+    const fn advance(&mut self, n: usize) {
         self.next_pos += n;
     }
+
+    /// The span covered by `vec[tokens]`
+    fn span(&self, tokens: Range<usize>) -> Span {
+        let end = tokens.end.min(self.vec.len());
+        let start = tokens.start.min(end);
+        Span {
+            bytes: self.offsets[start]..self.offsets[end],
+            graphemes: self.grapheme_offsets[start]..self.grapheme_offsets[end],
+        }
+    }
+
+    /// The index into `vec` of the token at which the `grapheme`-th grapheme cluster lies
+    fn token_index(&self, grapheme: usize) -> usize {
+        self.grapheme_offsets.partition_point(|&g| g < grapheme)
+    }
+
+    /// Removes `vec[i]`, letting `vec[i - 1]` cover the removed part of the original string
+    fn remove(&mut self, i: usize) {
+        self.vec.remove(i);
+        self.offsets.remove(i);
+        self.grapheme_offsets.remove(i);
+    }
+}
+
+impl VecAndIndex<Result<pinyin_token::PinyinToken, ParseError>> {
+    /// Replaces the tokens that `read` recognizes with the tokens of the text it returns.
+    /// `read(self, i)` gives the number of tokens recognized from `vec[i]` onwards, and what to replace them with.
+    /// The first of the new tokens spans the whole of the replaced ones, and the rest are empty.
+    /// An error replaces the tokens with a single erroneous one.
+    fn replace_with(
+        &mut self,
+        strictness: Strictness,
+        mut read: impl FnMut(&Self, usize) -> Option<(usize, Result<String, ParseError>)>,
+    ) {
+        use pinyin_token::{Alphabet, PinyinToken};
+        let mut vec = vec![];
+        let mut offsets = vec![];
+        let mut grapheme_offsets = vec![];
+        let mut i = 0;
+        while i < self.vec.len() {
+            let Some((len, text)) = read(self, i) else {
+                vec.push(self.vec[i].clone());
+                offsets.push(self.offsets[i]);
+                grapheme_offsets.push(self.grapheme_offsets[i]);
+                i += 1;
+                continue;
+            };
+
+            let span = self.span(i..i + len);
+            let text = match text {
+                Ok(text) => text,
+                Err(e) => {
+                    vec.push(Err(e));
+                    offsets.push(span.bytes.start);
+                    grapheme_offsets.push(span.graphemes.start);
+                    i += len;
+                    continue;
+                }
+            };
+            let tokens = text
+                .graphemes(true)
+                .map(|g| pinyin_token::to_token(g, &span, strictness))
+                .collect::<Vec<_>>();
+
+            // keep `xī'ān` from being read as `xiān`
+            if matches!(vec.last(), Some(Ok(PinyinToken::Alph(_))))
+                && matches!(
+                    tokens.first(),
+                    Some(Ok(PinyinToken::Alph(alph)))
+                        if matches!(alph.alphabet, Alphabet::A | Alphabet::E | Alphabet::O)
+                )
+            {
+                vec.push(Ok(PinyinToken::Apostrophe));
+                offsets.push(span.bytes.start);
+                grapheme_offsets.push(span.graphemes.start);
+            }
+
+            for (j, token) in tokens.into_iter().enumerate() {
+                vec.push(token);
+                if j == 0 {
+                    offsets.push(span.bytes.start);
+                    grapheme_offsets.push(span.graphemes.start);
+                } else {
+                    offsets.push(span.bytes.end);
+                    grapheme_offsets.push(span.graphemes.end);
+                }
+            }
+            i += len;
+        }
+        offsets.push(self.offsets[self.vec.len()]);
+        grapheme_offsets.push(self.grapheme_offsets[self.vec.len()]);
+
+        self.vec = vec;
+        self.offsets = offsets;
+        self.grapheme_offsets = grapheme_offsets;
+    }
+}
+
+/// Splits `s` into syllables that `read` recognizes, trying the longer ones first.
+/// `read(i, syllable)` is given where in `s` the syllable begins.
+/// Returns the lengths of the syllables along with what `read` made of them.
+fn segment<T: Clone>(
+    s: &[char],
+    max_len: usize,
+    read: impl Fn(usize, &[char]) -> Option<T>,
+) -> Option<Vec<(usize, T)>> {
+    // `first[i]` is the first syllable of the segmentation of `s[i..]`, if any
+    let mut first: Vec<Option<(usize, T)>> = vec![None; s.len() + 1];
+    for i in (0..s.len()).rev() {
+        first[i] = (1..=max_len.min(s.len() - i)).rev().find_map(|len| {
+            if i + len < s.len() && first[i + len].is_none() {
+                return None;
+            }
+            read(i, &s[i..i + len]).map(|t| (len, t))
+        });
+    }
+
+    let mut ans = vec![];
+    let mut i = 0;
+    while i < s.len() {
+        let (len, t) = first[i].take()?;
+        ans.push((len, t));
+        i += len;
+    }
+    Some(ans)
 }
 
 pub struct PinyinParserIterWithSplitR {
     iter: PinyinParserIter,
-    next_is_r: bool,
+    /// `r`, or `R` if capitalization is preserved
+    next_r: Option<char>,
 }
 
 impl Iterator for PinyinParserIterWithSplitR {
     type Item = String;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.next_is_r {
-            self.next_is_r = false;
-            return Some("r".to_owned());
+        if let Some(r) = self.next_r.take() {
+            return Some(r.to_string());
         }
 
         let ans = self.iter.next()?;
 
         // r should be split off from ans, unless they are "er", "ēr", "ér", "ěr", or "èr"
-        if matches!(&ans[..], "er" | "ēr" | "ér" | "ěr" | "èr") {
+        if matches!(&ans.to_lowercase()[..], "er" | "ēr" | "ér" | "ěr" | "èr") {
             return Some(ans);
         }
 
-        if let Some(rest) = ans.strip_suffix('r') {
-            self.next_is_r = true;
-            return Some(rest.to_owned());
+        for r in ['r', 'R'] {
+            if let Some(rest) = ans.strip_suffix(r) {
+                self.next_r = Some(r);
+                return Some(rest.to_owned());
+            }
         }
 
         Some(ans)
@@ -286,7 +903,7 @@ impl PinyinParserIter {
     pub const fn split_erhua(self) -> PinyinParserIterWithSplitR {
         PinyinParserIterWithSplitR {
             iter: self,
-            next_is_r: false,
+            next_r: None,
         }
     }
 }
@@ -294,9 +911,214 @@ impl PinyinParserIter {
 impl Iterator for PinyinParserIter {
     type Item = String;
 
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|r| r.unwrap_or_else(|e| panic!("{e}")))
+    }
+}
+
+impl Iterator for PinyinParserTryIter {
+    type Item = Result<String, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_preserved_item()
+            .map(|r| r.map(|(_, item)| self.configs.render(&item)))
+    }
+}
+
+impl Iterator for PinyinParserSpannedIter {
+    type Item = Result<(Range<usize>, String), ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let configs = self.inner.configs;
+        self.inner
+            .next_preserved_item()
+            .map(|r| r.map(|(span, item)| (span.bytes, configs.render(&item))))
+    }
+}
+
+impl Iterator for PinyinParserLossyIter {
+    type Item = LossyItem;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.inner.parse_next()? {
+                Ok((_, item)) => {
+                    if self.inner.configs.preserves(&item) {
+                        return Some(LossyItem::Parsed(self.inner.configs.render(&item)));
+                    }
+                }
+                Err(reason) => {
+                    let span = self.inner.skip_invalid(&reason);
+                    return Some(LossyItem::Invalid {
+                        text: self.inner.source[span.bytes].to_owned(),
+                        reason,
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl PinyinParserTryIter {
+    /// Skips the region in which `err` happened, up to the next plausible syllable boundary,
+    /// so that the parsing can resume from there. Returns the skipped region.
+    fn skip_invalid(&mut self, err: &ParseError) -> Span {
+        use pinyin_token::PinyinToken::Alph;
+        let graphemes = &err.span().graphemes;
+        let mut end = match err {
+            // the unexpected token itself is a fine boundary; leave it
+            ParseError::UnexpectedTokenAfterInitial { .. } => self.it.token_index(graphemes.start),
+            _ => self.it.token_index(graphemes.end),
+        }
+        .max(self.start + 1);
+
+        // a misplaced apostrophe is skipped on its own
+        if !matches!(
+            err,
+            ParseError::UnexpectedApostrophe { .. } | ParseError::ApostropheNotFollowedByAEO { .. }
+        ) {
+            while match self.it.vec.get(end) {
+                Some(Ok(Alph(alph))) => !alph.capitalized,
+                Some(Err(_)) => true,
+                Some(Ok(_)) | None => false,
+            } {
+                end += 1;
+            }
+        }
+
+        let skipped = self.it.span(self.start..end);
+        self.it.next_pos = end;
+        self.state = ParserState::BeforeWordInitial;
+        skipped
+    }
+
+    fn next_item(&mut self) -> Option<Result<(Span, Token), ParseError>> {
+        if self.failed {
+            return None;
+        }
+        let ans = self.parse_next();
+        if matches!(ans, Some(Err(_))) {
+            self.failed = true;
+        }
+        ans
+    }
+
+    /// Same as `next_item`, but skips what the configuration does not ask to preserve
+    fn next_preserved_item(&mut self) -> Option<Result<(Span, Token), ParseError>> {
+        loop {
+            match self.next_item()? {
+                Ok((_, item)) if !self.configs.preserves(&item) => {}
+                ans => return Some(ans),
+            }
+        }
+    }
+
+    /// Builds a syllable that began at `self.start`
+    fn syllable(
+        &self,
+        initial: SpellingInitial,
+        fin: NonRhoticFinal,
+        tone: Tone,
+        erhua: bool,
+    ) -> Token {
+        use pinyin_token::{Alphabet, Diacritic, PinyinToken};
+        let letters = self.it.vec[self.start..self.it.next_pos.min(self.it.vec.len())]
+            .iter()
+            .filter_map(|token| match token {
+                Ok(PinyinToken::Alph(alph)) => Some(alph),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let all_caps = letters.iter().all(|alph| alph.capitalized);
+
+        let mut uppercase = 0;
+        let mut i = 0;
+        for alph in letters {
+            // `ŋ`, `ẑ`, `ĉ` and `ŝ` are spelled with two letters
+            let spelled_with_two = alph.alphabet == Alphabet::Ŋ
+                || (matches!(alph.alphabet, Alphabet::Z | Alphabet::C | Alphabet::S)
+                    && alph.diacritics.contains(&Diacritic::Circumflex));
+            if alph.capitalized {
+                uppercase |= 1 << i;
+            }
+            if spelled_with_two {
+                i += 1;
+                // `Ẑāŋ` is `Zhāng`, while `ẐĀŊ` is `ZHĀNG`
+                if all_caps {
+                    uppercase |= 1 << i;
+                }
+            }
+            i += 1;
+        }
+
+        Token::Syllable(Syllable {
+            initial,
+            fin,
+            tone,
+            erhua,
+            capitalized: uppercase & 1 != 0,
+            uppercase,
+        })
+    }
+
+    /// The syllable spelled out by `candidate`, in standard spelling.
+    /// In strict mode, a final written in its full form is an error.
+    fn candidate_syllable(
+        &self,
+        span: Span,
+        initial: SpellingInitial,
+        candidate: finals::Candidate,
+        erhua: bool,
+    ) -> Result<(Span, Token), ParseError> {
+        let (standard_initial, fin) = candidate.standard(initial);
+        let token = self.syllable(standard_initial, fin, candidate.tone, erhua);
+        match token {
+            Token::Syllable(syllable)
+                if self.configs.p_strict.is_strict()
+                    && (candidate.full_form || standard_initial != initial) =>
+            {
+                Err(ParseError::FullFormFinal {
+                    full_form: self.source[span.bytes.clone()].to_owned(),
+                    standard: syllable.to_string(),
+                    span,
+                })
+            }
+            token => Ok((span, token)),
+        }
+    }
+
+    /// From the beginning of the current item up to the current position
+    fn span_from_start(&self) -> Span {
+        self.it.span(self.start..self.it.next_pos)
+    }
+
+    /// The token that was just consumed
+    fn last_token_span(&self) -> Span {
+        self.it.span(self.it.next_pos - 1..self.it.next_pos)
+    }
+
+    /// Same as `parse_spelling`, but in strict mode a syllable that does not exist is an error
+    fn parse_next(&mut self) -> Option<Result<(Span, Token), ParseError>> {
+        match self.parse_spelling() {
+            Some(Ok((span, Token::Syllable(syllable))))
+                if self.configs.p_strict.is_strict()
+                    && !is_valid_syllable(syllable.initial, syllable.fin) =>
+            {
+                Some(Err(ParseError::InvalidSyllable {
+                    syllable: syllable.to_string(),
+                    span,
+                }))
+            }
+            ans => ans,
+        }
+    }
+
+    /// Reads the next token, checking only that it is spelled out correctly
     #[allow(clippy::too_many_lines)]
     #[allow(clippy::cognitive_complexity)]
-    fn next(&mut self) -> Option<Self::Item> {
+    fn parse_spelling(&mut self) -> Option<Result<(Span, Token), ParseError>> {
         use pinyin_token::Alphabet;
         use pinyin_token::PinyinToken::{
             Alph, Apostrophe, LightToneMarker, Others, Punctuation, Space,
@@ -305,55 +1127,87 @@ impl Iterator for PinyinParserIter {
             AfterSyllablePossiblyConsumingApostrophe, BeforeWordInitial, InitialParsed, ZCSParsed,
         };
         loop {
+            if matches!(
+                self.state,
+                BeforeWordInitial | AfterSyllablePossiblyConsumingApostrophe
+            ) {
+                self.start = self.it.next_pos;
+            }
             match (self.it.next(), self.state) {
+                (Some(Err(e)), _) => return Some(Err(e.clone())),
                 (
-                    b @ Some(LightToneMarker | Punctuation(_) | Apostrophe | Space(_) | Others(_)),
-                    a @ (InitialParsed(_) | ZCSParsed(_)),
-                ) => panic!("unexpected {b:?} found after parsing initial {a:?}"),
+                    Some(Ok(
+                        b @ (LightToneMarker | Punctuation(_) | Apostrophe | Space(_) | Others(_)),
+                    )),
+                    InitialParsed(_) | ZCSParsed(_),
+                ) => {
+                    let found = b.to_source_string();
+                    return Some(Err(ParseError::UnexpectedTokenAfterInitial {
+                        initial: self.state.spelled_initial(),
+                        found,
+                        span: self.last_token_span(),
+                    }));
+                }
                 (
-                    Some(LightToneMarker),
+                    Some(Ok(LightToneMarker)),
                     AfterSyllablePossiblyConsumingApostrophe | BeforeWordInitial,
-                ) => continue, // just ignore it
+                ) => return Some(Ok((self.span_from_start(), Token::LightToneMarker))),
 
                 (
-                    Some(Apostrophe),
+                    Some(Ok(Apostrophe)),
                     AfterSyllablePossiblyConsumingApostrophe | BeforeWordInitial,
-                ) => panic!("unexpected apostrophe found at the beginning of a word"),
+                ) => {
+                    if self.state == AfterSyllablePossiblyConsumingApostrophe {
+                        // In the strict mode, `a`, `e` or `o` must follow the apostrophe
+                        if self.configs.p_strict.is_strict() && !self.it.a_e_o_follows(0) {
+                            return Some(Err(ParseError::ApostropheNotFollowedByAEO {
+                                span: self.last_token_span(),
+                            }));
+                        }
+                        self.state = BeforeWordInitial;
+                        return Some(Ok((self.span_from_start(), Token::Apostrophe)));
+                    }
+                    return Some(Err(ParseError::UnexpectedApostrophe {
+                        span: self.last_token_span(),
+                    }));
+                }
                 (None, AfterSyllablePossiblyConsumingApostrophe | BeforeWordInitial) => {
                     return None
                 }
-                (None, InitialParsed(initial)) => {
-                    panic!("unexpected end of string found after {initial:?}");
+                (None, InitialParsed(_) | ZCSParsed(_)) => {
+                    return Some(Err(ParseError::UnexpectedEndAfterInitial {
+                        initial: self.state.spelled_initial(),
+                        span: self.span_from_start(),
+                    }))
                 }
-                (None, ZCSParsed(zcs)) => panic!("unexpected end of string found after {zcs:?}"),
                 (
-                    Some(Punctuation(s)),
+                    Some(Ok(Punctuation(s))),
                     BeforeWordInitial | AfterSyllablePossiblyConsumingApostrophe,
                 ) => {
-                    if self.configs.p_preserve_punctuations {
-                        self.state = BeforeWordInitial;
-                        return Some((*s).clone());
-                    }
-                    continue;
+                    self.state = BeforeWordInitial;
+                    let s = Token::Punctuation((*s).clone());
+                    return Some(Ok((self.span_from_start(), s)));
                 }
-                (Some(Space(s)), BeforeWordInitial | AfterSyllablePossiblyConsumingApostrophe) => {
-                    if self.configs.p_preserve_spaces {
-                        self.state = BeforeWordInitial;
-                        return Some((*s).clone());
-                    }
-                    continue;
+                (
+                    Some(Ok(Space(s))),
+                    BeforeWordInitial | AfterSyllablePossiblyConsumingApostrophe,
+                ) => {
+                    self.state = BeforeWordInitial;
+                    let s = Token::Space((*s).clone());
+                    return Some(Ok((self.span_from_start(), s)));
                 }
 
-                (Some(Others(s)), BeforeWordInitial | AfterSyllablePossiblyConsumingApostrophe) => {
-                    if self.configs.p_preserve_miscellaneous {
-                        self.state = BeforeWordInitial;
-                        return Some((*s).clone());
-                    }
-                    continue;
+                (
+                    Some(Ok(Others(s))),
+                    BeforeWordInitial | AfterSyllablePossiblyConsumingApostrophe,
+                ) => {
+                    self.state = BeforeWordInitial;
+                    let s = Token::Other((*s).clone());
+                    return Some(Ok((self.span_from_start(), s)));
                 }
 
                 (
-                    Some(Alph(alph)),
+                    Some(Ok(Alph(alph))),
                     BeforeWordInitial | AfterSyllablePossiblyConsumingApostrophe,
                 ) => match alph.alphabet {
                     Alphabet::B => self.state = InitialParsed(SpellingInitial::B),
@@ -362,7 +1216,12 @@ impl Iterator for PinyinParserIter {
                         if alph.diacritics.is_empty() {
                             self.state = InitialParsed(SpellingInitial::M);
                         } else {
-                            return Some(alph.to_str(self.configs.p_strict));
+                            let s = Token::syllabic_nasal(
+                                alph,
+                                NonRhoticFinal::M,
+                                self.configs.p_strict,
+                            );
+                            return Some(Ok((self.span_from_start(), s)));
                         }
                     }
                     Alphabet::F => self.state = InitialParsed(SpellingInitial::F),
@@ -372,7 +1231,12 @@ impl Iterator for PinyinParserIter {
                         if alph.diacritics.is_empty() {
                             self.state = InitialParsed(SpellingInitial::N);
                         } else {
-                            return Some(alph.to_str(self.configs.p_strict));
+                            let s = Token::syllabic_nasal(
+                                alph,
+                                NonRhoticFinal::N,
+                                self.configs.p_strict,
+                            );
+                            return Some(Ok((self.span_from_start(), s)));
                         }
                     }
                     Alphabet::L => self.state = InitialParsed(SpellingInitial::L),
@@ -394,7 +1258,11 @@ impl Iterator for PinyinParserIter {
                         ) {
                             self.state = InitialParsed(SpellingInitial::ZH);
                         } else {
-                            return Some(alph.to_str(self.configs.p_strict));
+                            let s = Token::Other(alph.to_str_preserving_capitalization(
+                                self.configs.p_preserve_capitalization,
+                                self.configs.p_strict,
+                            ));
+                            return Some(Ok((self.span_from_start(), s)));
                         }
                     }
                     Alphabet::C => {
@@ -406,7 +1274,11 @@ impl Iterator for PinyinParserIter {
                         ) {
                             self.state = InitialParsed(SpellingInitial::CH);
                         } else {
-                            return Some(alph.to_str(self.configs.p_strict));
+                            let s = Token::Other(alph.to_str_preserving_capitalization(
+                                self.configs.p_preserve_capitalization,
+                                self.configs.p_strict,
+                            ));
+                            return Some(Ok((self.span_from_start(), s)));
                         }
                     }
                     Alphabet::S => {
@@ -418,7 +1290,11 @@ impl Iterator for PinyinParserIter {
                         ) {
                             self.state = InitialParsed(SpellingInitial::SH);
                         } else {
-                            return Some(alph.to_str(self.configs.p_strict));
+                            let s = Token::Other(alph.to_str_preserving_capitalization(
+                                self.configs.p_preserve_capitalization,
+                                self.configs.p_strict,
+                            ));
+                            return Some(Ok((self.span_from_start(), s)));
                         }
                     }
                     Alphabet::A | Alphabet::E | Alphabet::O => {
@@ -426,13 +1302,31 @@ impl Iterator for PinyinParserIter {
                         self.state = InitialParsed(SpellingInitial::ZeroAEO);
                     }
 
-                    Alphabet::I | Alphabet::U | Alphabet::Ŋ => panic!(
-                        "unexpected alphabet {:?} found at the beginning of a word",
-                        alph.alphabet,
-                    ),
+                    Alphabet::I | Alphabet::U | Alphabet::Ŋ => {
+                        let found = alph.to_str(self.configs.p_strict);
+
+                        // `i` and `u` may only begin the full form of a syllable without an initial, such as `uen`
+                        if alph.alphabet != Alphabet::Ŋ {
+                            self.it.rewind(1);
+                            if self
+                                .it
+                                .get_candidates_without_rhotic(self.configs.p_strict)
+                                .iter()
+                                .any(|c| c.full_form && c.fits(SpellingInitial::ZeroAEO))
+                            {
+                                self.state = InitialParsed(SpellingInitial::ZeroAEO);
+                                continue;
+                            }
+                            self.it.advance(1);
+                        }
+                        return Some(Err(ParseError::UnexpectedAlphabetAtWordStart {
+                            found,
+                            span: self.last_token_span(),
+                        }));
+                    }
                 },
 
-                (Some(Alph(alph)), ZCSParsed(zcs)) => {
+                (Some(Ok(Alph(alph))), ZCSParsed(zcs)) => {
                     if alph.alphabet == Alphabet::H {
                         self.state = match zcs {
                             ZCS::Z => InitialParsed(SpellingInitial::ZH),
@@ -449,65 +1343,66 @@ impl Iterator for PinyinParserIter {
                     }
                 }
 
-                (Some(Alph(_)), InitialParsed(initial)) => {
+                (Some(Ok(Alph(_))), InitialParsed(initial)) => {
                     use finals::Candidate;
                     self.it.rewind(1);
-                    let candidates = self.it.get_candidates_without_rhotic(self.configs.p_strict);
+                    let mut candidates =
+                        self.it.get_candidates_without_rhotic(self.configs.p_strict);
 
-                    assert!(!candidates.is_empty(),
-                            "no adequate candidate for finals (-an, -ian, ...) is found, after the initial {initial:?}"
-                        );
+                    candidates.retain(|c| c.fits(initial));
 
-                    for Candidate { ŋ, fin, tone } in candidates.clone() {
-                        let fin_len = fin.len() - usize::from(ŋ); // ŋ accounts for ng, hence the len is shorter by 1
+                    // in strict mode, syllables that do not exist are tried only after all the others
+                    if self.configs.p_strict.is_strict() {
+                        candidates.sort_by_key(|c| {
+                            let (initial, fin) = c.standard(initial);
+                            !is_valid_syllable(initial, fin)
+                        });
+                    }
+
+                    if candidates.is_empty() {
+                        // a malformed character within the word is the real culprit, if any
+                        if let Some(e) = self.it.first_error_in_word() {
+                            return Some(Err(e.clone()));
+                        }
+                        return Some(Err(ParseError::NoCandidateForFinal {
+                            initial: initial.to_string(),
+                            span: self.it.span(self.it.next_pos..self.it.next_pos + 1),
+                        }));
+                    }
+
+                    for candidate in candidates.clone() {
+                        let fin_len = candidate.len();
                         self.it.advance(fin_len);
 
                         // ITERATOR IS TEMPORARILY ADVANCED HERE
                         match self.it.peek(0) {
                             None => {
+                                let span = self.span_from_start();
                                 self.it.advance(1);
                                 self.state = AfterSyllablePossiblyConsumingApostrophe;
-                                return Some(format!(
-                                    "{}{}",
-                                    initial,
-                                    finals::FinalWithTone { fin, tone }
-                                ));
+                                return Some(
+                                    self.candidate_syllable(span, initial, candidate, false),
+                                );
                             }
 
-                            Some(Apostrophe) => {
-                                self.it.advance(1);
-
-                                // In the strict mode, `a`, `e` or `o` must follow the apostrophe
-                                if self.configs.p_strict.is_strict() {
-                                    let a_e_o = match self.it.peek(0) {
-                                        Some(Alph(a)) => matches!(
-                                            a.alphabet,
-                                            Alphabet::A | Alphabet::E | Alphabet::O
-                                        ),
-                                        _ => false,
-                                    };
-
-                                    assert!(a_e_o, "In strict mode, an apostrophe must be followed by either 'a', 'e' or 'o'");
-                                }
-
+                            // the apostrophe is to be consumed as a token of its own
+                            Some(
+                                Err(_)
+                                | Ok(
+                                    Apostrophe | Punctuation(_) | LightToneMarker | Space(_)
+                                    | Others(_),
+                                ),
+                            ) => {
                                 self.state = AfterSyllablePossiblyConsumingApostrophe;
-                                return Some(format!(
-                                    "{}{}",
+                                return Some(self.candidate_syllable(
+                                    self.span_from_start(),
                                     initial,
-                                    finals::FinalWithTone { fin, tone }
+                                    candidate,
+                                    false,
                                 ));
                             }
 
-                            Some(Punctuation(_) | LightToneMarker | Space(_) | Others(_)) => {
-                                self.state = AfterSyllablePossiblyConsumingApostrophe;
-                                return Some(format!(
-                                    "{}{}",
-                                    initial,
-                                    finals::FinalWithTone { fin, tone }
-                                ));
-                            }
-
-                            Some(Alph(alph)) => match alph.alphabet {
+                            Some(Ok(Alph(alph))) => match alph.alphabet {
                                 Alphabet::A
                                 | Alphabet::E
                                 | Alphabet::I
@@ -516,14 +1411,13 @@ impl Iterator for PinyinParserIter {
                                 | Alphabet::Ŋ => {
                                     /* we have read too much or too little; this candidate is not good; ignore. */
                                     self.it.rewind(fin_len);
-                                    continue;
                                 }
 
                                 Alphabet::R =>
                                 /* possibly rhotic */
                                 {
                                     let vowel_follows = match self.it.peek(1) {
-                                        Some(Alph(a)) => matches!(
+                                        Some(Ok(Alph(a))) => matches!(
                                             a.alphabet,
                                             Alphabet::A
                                                 | Alphabet::E
@@ -538,19 +1432,21 @@ impl Iterator for PinyinParserIter {
                                         // peeking `r` was not needed
                                         // hence simply return
                                         self.state = AfterSyllablePossiblyConsumingApostrophe;
-                                        return Some(format!(
-                                            "{}{}",
+                                        return Some(self.candidate_syllable(
+                                            self.span_from_start(),
                                             initial,
-                                            finals::FinalWithTone { fin, tone }
+                                            candidate,
+                                            false,
                                         ));
                                     }
                                     // this is rhotic
                                     self.it.advance(1);
                                     self.state = AfterSyllablePossiblyConsumingApostrophe;
-                                    return Some(format!(
-                                        "{}{}r",
+                                    return Some(self.candidate_syllable(
+                                        self.span_from_start(),
                                         initial,
-                                        finals::FinalWithTone { fin, tone }
+                                        candidate,
+                                        true,
                                     ));
                                 }
 
@@ -558,7 +1454,7 @@ impl Iterator for PinyinParserIter {
                                 /* possibly g */
                                 {
                                     let vowel_follows = match self.it.peek(1) {
-                                        Some(Alph(a)) => matches!(
+                                        Some(Ok(Alph(a))) => matches!(
                                             a.alphabet,
                                             Alphabet::A
                                                 | Alphabet::E
@@ -573,20 +1469,20 @@ impl Iterator for PinyinParserIter {
                                         // peeking `g` was not needed
                                         // hence simply return
                                         self.state = AfterSyllablePossiblyConsumingApostrophe;
-                                        return Some(format!(
-                                            "{}{}",
+                                        return Some(self.candidate_syllable(
+                                            self.span_from_start(),
                                             initial,
-                                            finals::FinalWithTone { fin, tone }
+                                            candidate,
+                                            false,
                                         ));
                                     }
                                     // this candidate is wrong
                                     self.it.rewind(fin_len);
-                                    continue;
                                 }
 
                                 Alphabet::N => {
                                     let vowel_follows = match self.it.peek(1) {
-                                        Some(Alph(a)) => matches!(
+                                        Some(Ok(Alph(a))) => matches!(
                                             a.alphabet,
                                             Alphabet::A
                                                 | Alphabet::E
@@ -600,31 +1496,49 @@ impl Iterator for PinyinParserIter {
                                         // peeking `n` was not needed
                                         // hence simply return
                                         self.state = AfterSyllablePossiblyConsumingApostrophe;
-                                        return Some(format!(
-                                            "{}{}",
+                                        return Some(self.candidate_syllable(
+                                            self.span_from_start(),
                                             initial,
-                                            finals::FinalWithTone { fin, tone }
+                                            candidate,
+                                            false,
                                         ));
                                     }
                                     // this candidate is not good
                                     self.it.rewind(fin_len);
-                                    continue;
                                 }
 
                                 _ => {
                                     self.state = AfterSyllablePossiblyConsumingApostrophe;
-                                    return Some(format!(
-                                        "{}{}",
+                                    return Some(self.candidate_syllable(
+                                        self.span_from_start(),
                                         initial,
-                                        finals::FinalWithTone { fin, tone }
+                                        candidate,
+                                        false,
                                     ));
                                 }
                             },
                         }
                     }
-                    panic!(
-                        "no adequate candidate for finals (-an, -ian, ...) found, among possible candidates {candidates:?}"
-                    );
+                    if let Some(e) = self.it.first_error_in_word() {
+                        return Some(Err(e.clone()));
+                    }
+                    let longest = candidates.iter().map(|c| c.len()).max().unwrap_or(0);
+                    return Some(Err(ParseError::NoAdequateCandidate {
+                        span: self.it.span(self.start..self.it.next_pos + longest),
+                        candidates: candidates
+                            .iter()
+                            .map(|Candidate { fin, tone, .. }| {
+                                format!(
+                                    "{}{}",
+                                    initial,
+                                    finals::FinalWithTone {
+                                        fin: *fin,
+                                        tone: *tone
+                                    }
+                                )
+                            })
+                            .collect(),
+                    }));
                 }
             }
         }
@@ -632,6 +1546,10 @@ impl Iterator for PinyinParserIter {
 }
 
 mod finals;
+mod tone_number;
+mod tongyong_token;
+mod zhuyin_token;
+pub use finals::{NonRhoticFinal, Tone};
 
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -641,8 +1559,9 @@ enum ZCS {
     S,
 }
 
+/// The initial as spelled in pinyin; `y` and `w` are therefore regarded as initials
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-enum SpellingInitial {
+pub enum SpellingInitial {
     B,
     P,
     M,
@@ -666,6 +1585,8 @@ enum SpellingInitial {
     S,
     Y,
     W,
+
+    /// No initial; the syllable begins with `a`, `e` or `o`, or is a syllabic nasal such as `ń`
     ZeroAEO,
 }
 
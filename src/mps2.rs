@@ -0,0 +1,120 @@
+use crate::{finals, NonRhoticFinal, SpellingInitial, Syllable};
+
+/// Displays a [`Syllable`] in Mandarin Phonetic Symbols II, such as `jūng`. Created by [`Syllable::to_mps2`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct MpsII {
+    syllable: Syllable,
+}
+
+const fn initial(initial: SpellingInitial) -> &'static str {
+    use SpellingInitial::{
+        ZeroAEO, B, C, CH, D, F, G, H, J, K, L, M, N, P, Q, R, S, SH, T, W, X, Y, Z, ZH,
+    };
+    match initial {
+        B => "b",
+        P => "p",
+        M => "m",
+        F => "f",
+        D => "d",
+        T => "t",
+        N => "n",
+        L => "l",
+        G => "g",
+        K => "k",
+        H => "h",
+        J | ZH => "j",
+        Q | CH => "ch",
+        X | SH => "sh",
+        R => "r",
+        Z => "tz",
+        C => "ts",
+        S => "s",
+        Y | W | ZeroAEO => "",
+    }
+}
+
+/// The final as written without an initial, and after one
+const fn rhyme(fin: NonRhoticFinal) -> (&'static str, &'static str) {
+    use NonRhoticFinal as F;
+    match fin {
+        F::A => ("a", "a"),
+        F::O => ("o", "o"),
+        F::E => ("e", "e"),
+        F::Ê => ("ê", "ê"),
+        F::Ai => ("ai", "ai"),
+        F::Ei => ("ei", "ei"),
+        F::Ao => ("au", "au"),
+        F::Ou => ("ou", "ou"),
+        F::An => ("an", "an"),
+        F::En => ("en", "en"),
+        F::Ang => ("ang", "ang"),
+        F::Eng => ("eng", "eng"),
+        F::Ong => ("weng", "ung"),
+        F::I => ("yi", "i"),
+        F::Ia => ("ya", "ia"),
+        F::Io => ("yo", "io"),
+        F::Ie => ("ye", "ie"),
+        F::Iao => ("yau", "iau"),
+        F::Iu => ("you", "iou"),
+        F::Ian => ("yan", "ien"),
+        F::In => ("yin", "in"),
+        F::Iang => ("yang", "iang"),
+        F::Ing => ("ying", "ing"),
+        F::Iong => ("yung", "iung"),
+        F::U => ("wu", "u"),
+        F::Ua => ("wa", "ua"),
+        F::Uo => ("wo", "uo"),
+        F::Uai => ("wai", "uai"),
+        F::Ui => ("wei", "uei"),
+        F::Uan => ("wan", "uan"),
+        F::Un => ("wen", "uen"),
+        F::Uang => ("wang", "uang"),
+        F::Ü => ("yu", "iu"),
+        F::Ue | F::Üe => ("yue", "iue"),
+        F::Üan => ("yuan", "iuan"),
+        F::Ün => ("yun", "iun"),
+        F::M => ("m", "m"),
+        F::N => ("n", "n"),
+    }
+}
+
+impl std::fmt::Display for MpsII {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use SpellingInitial as I;
+        let Syllable {
+            initial: i,
+            fin,
+            tone,
+            erhua,
+            ..
+        } = self.syllable;
+        let underlying = finals::underlying(i, fin);
+        let spelling = match (i, underlying) {
+            (I::ZH | I::CH | I::SH, NonRhoticFinal::I) => format!("{}r", initial(i)),
+            (I::R, NonRhoticFinal::I) => "r".to_owned(),
+            (I::Z, NonRhoticFinal::I) => "tz".to_owned(),
+            (I::C | I::S, NonRhoticFinal::I) => format!("{}z", initial(i)),
+            (I::ZeroAEO, NonRhoticFinal::E) if erhua => "er".to_owned(),
+            (I::Y | I::W | I::ZeroAEO, _) => rhyme(underlying).0.to_owned(),
+            _ => format!("{}{}", initial(i), rhyme(underlying).1),
+        };
+        let erhua = if erhua && spelling != "er" { "r" } else { "" };
+        write!(f, "{}{erhua}", finals::with_tone_mark(&spelling, tone))
+    }
+}
+
+impl Syllable {
+    /// Renders the syllable in Mandarin Phonetic Symbols II. The erhua is marked by a trailing `r`.
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let mps2 = PinyinParser::new()
+    ///     .try_parse_syllables("Zhōngguó xuéxí shì zì")
+    ///     .map(|s| s.unwrap().to_mps2().to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(mps2, vec!["jūng", "guó", "shiué", "shí", "shr̀", "tz̀"]);
+    /// ```
+    #[must_use]
+    pub const fn to_mps2(self) -> MpsII {
+        MpsII { syllable: self }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{
+    finals, NonRhoticFinal, ParseError, PinyinParser, Romanization, SpellingInitial, Syllable,
+};
+
+/// Displays a [`Syllable`] in the Palladius system of Cyrillic transcription, such as `чжун`.
+/// Created by [`Syllable::to_palladius`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Palladius {
+    syllable: Syllable,
+}
+
+const fn initial(initial: SpellingInitial) -> &'static str {
+    use SpellingInitial::{
+        ZeroAEO, B, C, CH, D, F, G, H, J, K, L, M, N, P, Q, R, S, SH, T, W, X, Y, Z, ZH,
+    };
+    match initial {
+        B => "б",
+        P => "п",
+        M => "м",
+        F => "ф",
+        D => "д",
+        T => "т",
+        N => "н",
+        L => "л",
+        G => "г",
+        K => "к",
+        H => "х",
+        J | Z => "цз",
+        Q | C => "ц",
+        X | S => "с",
+        ZH => "чж",
+        CH => "ч",
+        SH => "ш",
+        R => "ж",
+        Y | W | ZeroAEO => "",
+    }
+}
+
+/// The final as written without an initial, and after one
+const fn rhyme(fin: NonRhoticFinal) -> (&'static str, &'static str) {
+    use NonRhoticFinal as F;
+    match fin {
+        F::A => ("а", "а"),
+        F::O => ("о", "о"),
+        F::E | F::Ê => ("э", "э"),
+        F::Ai => ("ай", "ай"),
+        F::Ei => ("эй", "эй"),
+        F::Ao => ("ао", "ао"),
+        F::Ou => ("оу", "оу"),
+        F::An => ("ань", "ань"),
+        F::En => ("энь", "энь"),
+        F::Ang => ("ан", "ан"),
+        F::Eng => ("эн", "эн"),
+        F::Ong => ("вэн", "ун"),
+        F::I => ("и", "и"),
+        F::Ia => ("я", "я"),
+        F::Io => ("ё", "ё"),
+        F::Ie => ("е", "е"),
+        F::Iao => ("яо", "яо"),
+        F::Iu => ("ю", "ю"),
+        F::Ian => ("янь", "янь"),
+        F::In => ("инь", "инь"),
+        F::Iang => ("ян", "ян"),
+        F::Ing => ("ин", "ин"),
+        F::Iong => ("юн", "юн"),
+        F::U => ("у", "у"),
+        F::Ua => ("ва", "уа"),
+        F::Uo => ("во", "о"),
+        F::Uai => ("вай", "уай"),
+        F::Ui => ("вэй", "уй"),
+        F::Uan => ("вань", "уань"),
+        F::Un => ("вэнь", "унь"),
+        F::Uang => ("ван", "уан"),
+        F::Ü => ("юй", "юй"),
+        F::Ue | F::Üe => ("юэ", "юэ"),
+        F::Üan => ("юань", "юань"),
+        F::Ün => ("юнь", "юнь"),
+        F::M => ("м", "м"),
+        F::N => ("н", "н"),
+    }
+}
+
+impl std::fmt::Display for Palladius {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use NonRhoticFinal as F;
+        use SpellingInitial as I;
+        let Syllable {
+            initial: i,
+            fin,
+            erhua,
+            capitalized,
+            ..
+        } = self.syllable;
+        let underlying = finals::underlying(i, fin);
+        let spelling = match (i, underlying) {
+            (I::Z | I::C | I::S, F::I) => format!("{}ы", initial(i)),
+            (I::ZeroAEO, F::E) if erhua => "эр".to_owned(),
+            (I::Y | I::W | I::ZeroAEO, _) => rhyme(underlying).0.to_owned(),
+            _ => format!("{}{}", initial(i), rhyme(underlying).1),
+        };
+        let erhua = if erhua && spelling != "эр" {
+            "р"
+        } else {
+            ""
+        };
+        let mut chars = spelling.chars();
+        if capitalized {
+            if let Some(c) = chars.next() {
+                write!(f, "{}", c.to_uppercase())?;
+            }
+        }
+        write!(f, "{}{erhua}", chars.as_str())
+    }
+}
+
+impl Syllable {
+    /// Renders the syllable in the Palladius system, capitalized if the syllable was.
+    /// The tone is not written. Use [`PinyinParser::try_to_palladius`] to transcribe whole words.
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let palladius = PinyinParser::new()
+    ///     .try_parse_syllables("Běijīng Shànghǎi zì")
+    ///     .map(|s| s.unwrap().to_palladius().to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(palladius, vec!["Бэй", "цзин", "Шан", "хай", "цзы"]);
+    /// ```
+    #[must_use]
+    pub const fn to_palladius(self) -> Palladius {
+        Palladius { syllable: self }
+    }
+}
+
+/// The Palladius system with the syllables of a word written together
+struct PalladiusWords;
+
+impl Romanization for PalladiusWords {
+    fn syllable(&self, syllable: Syllable) -> String {
+        syllable.to_palladius().to_string()
+    }
+
+    fn separator(&self, previous: &str, next: &str) -> String {
+        let starts_with_vowel = next
+            .chars()
+            .next()
+            .is_some_and(|c| "аоуэеёюяиАОУЭЕЁЮЯИ".contains(c));
+        if starts_with_vowel && previous.ends_with('н') {
+            "ъ".to_owned()
+        } else {
+            String::new()
+        }
+    }
+}
+
+impl PinyinParser {
+    /// Converts `s` into the Palladius system, writing the syllables of a word together.
+    /// Within a word, a final `н` followed by a vowel is written `нъ`, so that `Chang'an` becomes `Чанъань`
+    /// rather than `Чанань`; the final `нь` already keeps the syllables apart.
+    /// Spaces, punctuations and other characters are kept as they are.
+    /// # Errors
+    /// Returns the first error that [`PinyinParser::try_parse`] finds.
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// assert_eq!(
+    ///     PinyinParser::new()
+    ///         .try_to_palladius("Zhōngguó, Cháng'ān, Yán'ān")
+    ///         .unwrap(),
+    ///     "Чжунго, Чанъань, Яньань"
+    /// );
+    /// ```
+    pub fn try_to_palladius(self, s: &str) -> Result<String, ParseError> {
+        self.try_romanize(s, &PalladiusWords)
+    }
+}
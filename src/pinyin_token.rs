@@ -1,4 +1,4 @@
-use crate::Strictness;
+use crate::{ParseError, Span, Strictness};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PinyinToken {
@@ -9,6 +9,20 @@ pub enum PinyinToken {
     Space(String),
     Others(String),
 }
+
+impl PinyinToken {
+    /// Spells the token back, as faithfully as possible. Used for error messages.
+    #[must_use]
+    pub fn to_source_string(&self) -> String {
+        match self {
+            Self::Alph(alph) => alph.to_str_preserving_capitalization(true, Strictness::Strict),
+            Self::LightToneMarker => "·".to_owned(),
+            Self::Apostrophe => "'".to_owned(),
+            Self::Punctuation(s) | Self::Space(s) | Self::Others(s) => s.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Diacritic {
     Umlaut,     /* ü */
@@ -219,9 +233,14 @@ macro_rules! cap {
 }
 
 #[allow(clippy::too_many_lines)]
-pub fn to_token(s: &str, strictness: Strictness) -> PinyinToken {
+pub fn to_token(s: &str, span: &Span, strictness: Strictness) -> Result<PinyinToken, ParseError> {
     use Alphabet::{A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, W, X, Y, Z, Ŋ};
     use Diacritic::{Acute, Breve, Circumflex, Grave, Hacek, Macron, Umlaut};
+    let lookalike = |found, looks_like| ParseError::LookalikeCharacter {
+        found,
+        looks_like,
+        span: span.clone(),
+    };
     let mut it = s.chars();
     let base = match it.next() {
         None => panic!("to_token received empty string"),
@@ -305,24 +324,24 @@ pub fn to_token(s: &str, strictness: Strictness) -> PinyinToken {
         Some('ǹ') => low!(N, Grave), Some('Ǹ') => cap!(N, Grave),
 
         // wrong
-        Some('\u{0261}') /* IPA's /g/ */ => if strictness.is_strict() { panic!("'\u{0261}' looks like 'g', but it is not.") } else { low!(G) },
-        Some(a @ ('\u{0251}' /* IPA's /ɑ/ */ | 'α')) => if strictness.is_strict() { panic!("'{a}' looks like 'a', but it is not.") } else { low!(A) },
-        Some('ο') => if strictness.is_strict() { panic!("'ο' looks like 'o', but it is not.") } else { low!(O) },
+        Some('\u{0261}') /* IPA's /g/ */ => if strictness.is_strict() { return Err(lookalike('\u{0261}', 'g')) } else { low!(G) },
+        Some(a @ ('\u{0251}' /* IPA's /ɑ/ */ | 'α')) => if strictness.is_strict() { return Err(lookalike(a, 'a')) } else { low!(A) },
+        Some('ο') => if strictness.is_strict() { return Err(lookalike('ο', 'o')) } else { low!(O) },
         // greek capital letters
-        Some('Α') => if strictness.is_strict() { panic!("'Α' looks like 'A', but it is not.") } else {cap!(A)}, 
-        Some('Β') => if strictness.is_strict() { panic!("'Β' looks like 'B', but it is not.") } else {cap!(B)}, 
-        Some('Ε') => if strictness.is_strict() { panic!("'Ε' looks like 'E', but it is not.") } else {cap!(E)},
-        Some('Ζ') => if strictness.is_strict() { panic!("'Ζ' looks like 'Z', but it is not.") } else {cap!(Z)}, 
-        Some('Η') => if strictness.is_strict() { panic!("'Η' looks like 'H', but it is not.") } else {cap!(H)}, 
-        Some('Ι') => if strictness.is_strict() { panic!("'Ι' looks like 'I', but it is not.") } else {cap!(I)},
-        Some('Κ') => if strictness.is_strict() { panic!("'Κ' looks like 'K', but it is not.") } else {cap!(K)}, 
-        Some('Μ') => if strictness.is_strict() { panic!("'Μ' looks like 'M', but it is not.") } else {cap!(M)} , 
-        Some('Ν') => if strictness.is_strict() { panic!("'Ν' looks like 'N', but it is not.") } else {cap!(N)},
-        Some('Ο') => if strictness.is_strict() { panic!("'Ο' looks like 'O', but it is not.") } else {cap!(O)}, 
-        Some('Ρ') => if strictness.is_strict() { panic!("'Ρ' looks like 'P', but it is not.") } else {cap!(P)}, 
-        Some('Τ') => if strictness.is_strict() { panic!("'Τ' looks like 'T', but it is not.") } else {cap!(T)},
-        Some('Υ') => if strictness.is_strict() { panic!("'Υ' looks like 'Y', but it is not.") } else {cap!(Y)}, 
-        Some('Χ') => if strictness.is_strict() { panic!("'Χ' looks like 'X', but it is not.") } else {cap!(X)},
+        Some('Α') => if strictness.is_strict() { return Err(lookalike('Α', 'A')) } else {cap!(A)}, 
+        Some('Β') => if strictness.is_strict() { return Err(lookalike('Β', 'B')) } else {cap!(B)}, 
+        Some('Ε') => if strictness.is_strict() { return Err(lookalike('Ε', 'E')) } else {cap!(E)},
+        Some('Ζ') => if strictness.is_strict() { return Err(lookalike('Ζ', 'Z')) } else {cap!(Z)}, 
+        Some('Η') => if strictness.is_strict() { return Err(lookalike('Η', 'H')) } else {cap!(H)}, 
+        Some('Ι') => if strictness.is_strict() { return Err(lookalike('Ι', 'I')) } else {cap!(I)},
+        Some('Κ') => if strictness.is_strict() { return Err(lookalike('Κ', 'K')) } else {cap!(K)}, 
+        Some('Μ') => if strictness.is_strict() { return Err(lookalike('Μ', 'M')) } else {cap!(M)} , 
+        Some('Ν') => if strictness.is_strict() { return Err(lookalike('Ν', 'N')) } else {cap!(N)},
+        Some('Ο') => if strictness.is_strict() { return Err(lookalike('Ο', 'O')) } else {cap!(O)}, 
+        Some('Ρ') => if strictness.is_strict() { return Err(lookalike('Ρ', 'P')) } else {cap!(P)}, 
+        Some('Τ') => if strictness.is_strict() { return Err(lookalike('Τ', 'T')) } else {cap!(T)},
+        Some('Υ') => if strictness.is_strict() { return Err(lookalike('Υ', 'Y')) } else {cap!(Y)}, 
+        Some('Χ') => if strictness.is_strict() { return Err(lookalike('Χ', 'X')) } else {cap!(X)},
 
         // others
         Some('·') => PinyinToken::LightToneMarker,
@@ -340,12 +359,12 @@ pub fn to_token(s: &str, strictness: Strictness) -> PinyinToken {
             for d in it {
                 match diacritic(d) {
                     Some(a) => alph.diacritics.push(a),
-                    _ => return PinyinToken::Others(s.to_owned()),
+                    _ => return Ok(PinyinToken::Others(s.to_owned())),
                 }
             }
-            PinyinToken::Alph(alph)
+            Ok(PinyinToken::Alph(alph))
         }
-        _ => base,
+        _ => Ok(base),
     }
 }
 
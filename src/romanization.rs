@@ -0,0 +1,211 @@
+use crate::{
+    finals, NonRhoticFinal, ParseError, PinyinParser, SpellingInitial, Strictness, Syllable,
+    TableError, Token, Tone,
+};
+use std::collections::HashMap;
+
+/// A way of writing syllables, used by [`PinyinParser::try_romanize`] to convert whole texts.
+/// ```
+/// use pinyin_parser::{PinyinParser, Romanization, Syllable};
+/// struct Shouting;
+/// impl Romanization for Shouting {
+///     fn syllable(&self, syllable: Syllable) -> String {
+///         syllable.to_string().to_uppercase()
+///     }
+///     fn separator(&self, _previous: &str, _next: &str) -> String {
+///         "·".to_owned()
+///     }
+/// }
+/// assert_eq!(
+///     PinyinParser::new().try_romanize("nǐ hǎo, Zhōngguó", &Shouting).unwrap(),
+///     "NǏ HǍO, ZHŌNG·GUÓ"
+/// );
+/// ```
+pub trait Romanization {
+    /// Writes one syllable
+    fn syllable(&self, syllable: Syllable) -> String;
+
+    /// What goes between two syllables of the same word, given them as written. Nothing by default.
+    fn separator(&self, previous: &str, next: &str) -> String {
+        let _ = (previous, next);
+        String::new()
+    }
+}
+
+impl Syllable {
+    /// The final as pronounced, with the `y`, `w` and the `u` standing for `ü` taken into account,
+    /// such as [`NonRhoticFinal::Iong`] for `yong` and [`NonRhoticFinal::Ü`] for `ju`
+    /// ```
+    /// use pinyin_parser::{NonRhoticFinal, PinyinParser};
+    /// let finals = PinyinParser::new()
+    ///     .try_parse_syllables("yǒng jù wèi")
+    ///     .map(|s| s.unwrap().underlying_final())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(finals, vec![NonRhoticFinal::Iong, NonRhoticFinal::Ü, NonRhoticFinal::Ui]);
+    /// ```
+    #[must_use]
+    pub const fn underlying_final(self) -> NonRhoticFinal {
+        finals::underlying(self.initial, self.fin)
+    }
+}
+
+impl PinyinParser {
+    /// Converts `s` with `romanization`, putting [`Romanization::separator`] between the syllables of a word.
+    /// Spaces, punctuations and other characters are kept as they are.
+    /// # Errors
+    /// Returns the first error that [`PinyinParser::try_parse`] finds.
+    pub fn try_romanize<R: Romanization + ?Sized>(
+        self,
+        s: &str,
+        romanization: &R,
+    ) -> Result<String, ParseError> {
+        let mut ans = String::new();
+        let mut previous: Option<String> = None;
+        for token in self.try_parse_tokens(s) {
+            match token? {
+                Token::Syllable(syllable) => {
+                    let written = romanization.syllable(syllable);
+                    if let Some(previous) = &previous {
+                        ans += &romanization.separator(previous, &written);
+                    }
+                    ans += &written;
+                    previous = Some(written);
+                }
+                Token::Apostrophe | Token::LightToneMarker => {}
+                Token::Space(s) | Token::Punctuation(s) | Token::Other(s) => {
+                    ans += &s;
+                    previous = None;
+                }
+            }
+        }
+        Ok(ans)
+    }
+}
+
+/// A [`Romanization`] given by a table, so that a scheme can be added without writing code.
+///
+/// Each line of the table maps a syllable, spelled in toneless pinyin, to how it is written.
+/// The key may end with a tone number from 1 to 5, in which case the line applies to that tone only;
+/// otherwise it applies to every tone without a line of its own.
+/// A key may also end in the erhua `r`. Lines beginning with `#` are comments, and two directives are understood:
+/// - `@separator` followed by what goes between the syllables of a word, which is nothing if left out
+/// - `@erhua` followed by what is appended for the erhua when the table has no line for the syllable with it,
+///   which is `r` unless specified
+///
+/// A syllable missing from the table is written in pinyin, capitalized as it was.
+/// Otherwise the first letter is capitalized if the syllable was.
+///
+/// The table can be embedded with `include_str!`, or read from a file at run time.
+/// ```
+/// use pinyin_parser::{PinyinParser, RomanizationTable, Strictness};
+/// let table = "
+/// @separator -
+/// # a house scheme
+/// zhong  jong
+/// guo2   gwor
+/// guo    gwo
+/// hua    hwa
+/// "
+/// .parse::<RomanizationTable>()
+/// .unwrap();
+/// assert_eq!(
+///     PinyinParser::new().try_romanize("Zhōngguó, huàr, guǒ", &table).unwrap(),
+///     "Jong-gwor, hwar, gwo"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RomanizationTable {
+    entries: HashMap<(SpellingInitial, NonRhoticFinal, bool, Option<Tone>), String>,
+    separator: String,
+    erhua: Option<String>,
+}
+
+impl RomanizationTable {
+    /// What the table has for `syllable`, without the capitalization
+    fn lookup(&self, syllable: Syllable, erhua: bool) -> Option<&String> {
+        let key = |tone| (syllable.initial, syllable.underlying_final(), erhua, tone);
+        self.entries
+            .get(&key(Some(syllable.tone)))
+            .or_else(|| self.entries.get(&key(None)))
+    }
+}
+
+impl Romanization for RomanizationTable {
+    fn syllable(&self, syllable: Syllable) -> String {
+        let written = self.lookup(syllable, syllable.erhua).cloned().or_else(|| {
+            let base = self.lookup(syllable, false).filter(|_| syllable.erhua)?;
+            Some(format!("{base}{}", self.erhua.as_deref().unwrap_or("r")))
+        });
+        let Some(written) = written else {
+            return syllable.to_string_preserving_capitalization();
+        };
+        let mut chars = written.chars();
+        match chars.next() {
+            Some(c) if syllable.capitalized => c.to_uppercase().chain(chars).collect(),
+            _ => written,
+        }
+    }
+
+    fn separator(&self, _previous: &str, _next: &str) -> String {
+        self.separator.clone()
+    }
+}
+
+impl std::str::FromStr for RomanizationTable {
+    type Err = TableError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut table = Self::default();
+        for (i, line) in s.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once(char::is_whitespace)
+                .map_or((line, ""), |(key, value)| (key, value.trim()));
+            if let Some(directive) = key.strip_prefix('@') {
+                match directive {
+                    "separator" => value.clone_into(&mut table.separator),
+                    "erhua" => table.erhua = Some(value.to_owned()),
+                    _ => {
+                        return Err(TableError::UnknownDirective {
+                            line: line_number,
+                            directive: key.to_owned(),
+                        })
+                    }
+                }
+                continue;
+            }
+            if value.is_empty() {
+                return Err(TableError::MissingValue { line: line_number });
+            }
+            let invalid_key = || TableError::InvalidKey {
+                line: line_number,
+                key: key.to_owned(),
+            };
+            let has_tone = key.ends_with(|c: char| c.is_ascii_digit());
+            let mut syllables = PinyinParser::new()
+                .with_strictness(Strictness::Strict)
+                .accept_tone_numbers(true)
+                .try_parse_syllables(key);
+            let (Some(Ok(syllable)), None) = (syllables.next(), syllables.next()) else {
+                return Err(invalid_key());
+            };
+            if has_tone && key.ends_with('0') {
+                return Err(invalid_key());
+            }
+            table.entries.insert(
+                (
+                    syllable.initial,
+                    syllable.underlying_final(),
+                    syllable.erhua,
+                    has_tone.then_some(syllable.tone),
+                ),
+                value.to_owned(),
+            );
+        }
+        Ok(table)
+    }
+}
@@ -0,0 +1,274 @@
+use crate::pinyin_token::{Alphabet, Diacritic, PinyinToken};
+use crate::{
+    is_valid_syllable, NonRhoticFinal, PinyinParser, PinyinParserTryIter, SpellingInitial,
+    Syllable, Token,
+};
+
+/// Every way of splitting a word into syllables, yielded one at a time. Created by [`PinyinParser::segmentations`].
+#[derive(Debug, Clone)]
+pub struct Segmentations {
+    /// `edges[i]` lists the syllables that begin at the token `i`, along with where the next one begins
+    edges: Vec<Vec<(usize, Syllable)>>,
+
+    /// `complete[i]` tells whether the tokens from `i` on split up into syllables
+    complete: Vec<bool>,
+
+    /// The path being followed, as the token at which each syllable begins and the index of its edge
+    path: Vec<(usize, usize)>,
+    done: bool,
+
+    /// The reading that [`PinyinParser::parse`] gives, which is yielded first and then skipped
+    parsed: Option<Vec<Syllable>>,
+    parsed_yielded: bool,
+}
+
+impl PinyinParser {
+    /// Lists every way of splitting `word` into syllables, such as `xian` and `xi'an` for `xian`.
+    ///
+    /// An apostrophe in `word` always separates two syllables, and is read as [`PinyinParser::parse`] reads it.
+    /// The reading that [`PinyinParser::parse`] gives comes first, and the others follow with the longer syllables first.
+    /// In strict mode, syllables that do not exist are left out.
+    /// The readings are found one at a time, so taking the first few is quick even for a long word.
+    /// ```
+    /// use pinyin_parser::{PinyinParser, Strictness};
+    /// let readings = |word| {
+    ///     PinyinParser::new()
+    ///         .with_strictness(Strictness::Strict)
+    ///         .segmentations(word)
+    ///         .map(|s| s.iter().map(ToString::to_string).collect::<Vec<_>>().join("'"))
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(readings("xian"), vec!["xian", "xi'an"]);
+    /// assert_eq!(readings("fangan"), vec!["fan'gan", "fang'an"]);
+    /// assert_eq!(readings("fang'an"), vec!["fang'an"]);
+    /// assert!(readings("xq").is_empty());
+    /// ```
+    #[must_use]
+    pub fn segmentations(self, word: &str) -> Segmentations {
+        let mut it = self.try_parse(word);
+        let len = it.it.vec.len();
+        let edges = (0..len).map(|i| it.syllables_at(i)).collect::<Vec<_>>();
+        let mut complete = vec![false; len + 1];
+        complete[len] = true;
+        for i in (0..len).rev() {
+            complete[i] = edges[i].iter().any(|&(next, _)| complete[next]);
+        }
+
+        let done = len == 0 || !complete[0];
+        let mut ans = Segmentations {
+            edges,
+            complete,
+            path: vec![],
+            done,
+            parsed: None,
+            parsed_yielded: false,
+        };
+        if !ans.done {
+            ans.descend(0);
+        }
+        ans.parsed = self
+            .try_parse_syllables(word)
+            .collect::<Result<Vec<_>, _>>()
+            .ok()
+            .filter(|parsed| ans.contains(parsed));
+        ans
+    }
+}
+
+impl Segmentations {
+    /// Whether `syllables` is one of the paths through the lattice
+    fn contains(&self, syllables: &[Syllable]) -> bool {
+        let mut i = 0;
+        for syllable in syllables {
+            let Some(&(next, _)) = self
+                .edges
+                .get(i)
+                .and_then(|edges| edges.iter().find(|(_, s)| s == syllable))
+            else {
+                return false;
+            };
+            i = next;
+        }
+        i == self.edges.len()
+    }
+
+    /// Extends the path from the token `i` to the end, taking the first edge that leads there each time
+    fn descend(&mut self, mut i: usize) {
+        while i < self.edges.len() {
+            let Some(k) = self.edges[i]
+                .iter()
+                .position(|&(next, _)| self.complete[next])
+            else {
+                unreachable!("the tokens from {i} on split up into syllables");
+            };
+            self.path.push((i, k));
+            i = self.edges[i][k].0;
+        }
+    }
+
+    /// Moves on to the next path, or returns `false` if there is none
+    fn advance(&mut self) -> bool {
+        while let Some((i, k)) = self.path.pop() {
+            if let Some(k) =
+                (k + 1..self.edges[i].len()).find(|&k| self.complete[self.edges[i][k].0])
+            {
+                self.path.push((i, k));
+                self.descend(self.edges[i][k].0);
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl Iterator for Segmentations {
+    type Item = Vec<Syllable>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.parsed_yielded {
+            self.parsed_yielded = true;
+            if let Some(parsed) = &self.parsed {
+                return Some(parsed.clone());
+            }
+        }
+        while !self.done {
+            let path = self
+                .path
+                .iter()
+                .map(|&(i, k)| self.edges[i][k].1)
+                .collect::<Vec<_>>();
+            self.done = !self.advance();
+            if self.parsed.as_ref() != Some(&path) {
+                return Some(path);
+            }
+        }
+        None
+    }
+}
+
+impl PinyinParserTryIter {
+    /// Reads the initial at the current position, as the parser does at the beginning of a syllable.
+    /// A syllable without an initial takes up nothing here.
+    fn initial_at_start(&mut self) -> Option<SpellingInitial> {
+        use SpellingInitial as I;
+        let Some(Ok(PinyinToken::Alph(alph))) = self.it.next() else {
+            return None;
+        };
+        let (alphabet, plain) = (alph.alphabet, alph.diacritics.is_empty());
+        let circumflex = matches!(&alph.diacritics[..], [Diacritic::Circumflex]);
+        let h_follows = matches!(
+            self.it.peek(0),
+            Some(Ok(PinyinToken::Alph(alph))) if alph.alphabet == Alphabet::H
+        );
+        let initial = match alphabet {
+            Alphabet::B => I::B,
+            Alphabet::P => I::P,
+            Alphabet::M if plain => I::M,
+            Alphabet::F => I::F,
+            Alphabet::D => I::D,
+            Alphabet::T => I::T,
+            Alphabet::N if plain => I::N,
+            Alphabet::L => I::L,
+            Alphabet::G => I::G,
+            Alphabet::K => I::K,
+            Alphabet::H => I::H,
+            Alphabet::J => I::J,
+            Alphabet::Q => I::Q,
+            Alphabet::X => I::X,
+            Alphabet::R => I::R,
+            Alphabet::Y => I::Y,
+            Alphabet::W => I::W,
+            Alphabet::Z if circumflex || (plain && h_follows) => I::ZH,
+            Alphabet::C if circumflex || (plain && h_follows) => I::CH,
+            Alphabet::S if circumflex || (plain && h_follows) => I::SH,
+            Alphabet::Z if plain => I::Z,
+            Alphabet::C if plain => I::C,
+            Alphabet::S if plain => I::S,
+            Alphabet::A | Alphabet::E | Alphabet::O | Alphabet::I | Alphabet::U => {
+                self.it.rewind(1);
+                I::ZeroAEO
+            }
+            _ => return None,
+        };
+        if plain && matches!(initial, I::ZH | I::CH | I::SH) {
+            self.it.advance(1);
+        }
+        Some(initial)
+    }
+
+    /// The syllables that may begin at the token `i`, each with the token at which the next syllable begins.
+    /// Every candidate final is taken, not only the one the parser would settle on.
+    fn syllables_at(&mut self, i: usize) -> Vec<(usize, Syllable)> {
+        let strictness = self.configs.p_strict;
+        self.start = i;
+        self.it.next_pos = i;
+
+        // `ḿ`, `ń` and the like
+        if let Some(Ok(PinyinToken::Alph(alph))) = self.it.peek(0) {
+            let fin = match alph.alphabet {
+                Alphabet::M => Some(NonRhoticFinal::M),
+                Alphabet::N => Some(NonRhoticFinal::N),
+                _ => None,
+            };
+            if let Some(fin) = fin.filter(|_| !alph.diacritics.is_empty()) {
+                let Token::Syllable(syllable) = Token::syllabic_nasal(alph, fin, strictness) else {
+                    return vec![];
+                };
+                return self
+                    .next_syllable_at(i + 1)
+                    .map(|next| (next, syllable))
+                    .into_iter()
+                    .collect();
+            }
+        }
+
+        let Some(initial) = self.initial_at_start() else {
+            return vec![];
+        };
+        let fin_start = self.it.next_pos;
+        let mut ans = vec![];
+        for candidate in self.it.get_candidates_without_rhotic(strictness) {
+            if !candidate.fits(initial) {
+                continue;
+            }
+            let end = fin_start + candidate.len();
+            let r_follows = matches!(
+                self.it.vec.get(end),
+                Some(Ok(PinyinToken::Alph(alph)))
+                    if alph.alphabet == Alphabet::R && alph.diacritics.is_empty()
+            );
+            for erhua in [false, true] {
+                if erhua && !r_follows {
+                    continue;
+                }
+                let end = end + usize::from(erhua);
+                self.it.next_pos = end;
+                let span = self.it.span(i..end);
+                let Ok((_, Token::Syllable(syllable))) =
+                    self.candidate_syllable(span, initial, candidate, erhua)
+                else {
+                    continue;
+                };
+                if strictness.is_strict() && !is_valid_syllable(syllable.initial, syllable.fin) {
+                    continue;
+                }
+                if let Some(next) = self.next_syllable_at(end) {
+                    ans.push((next, syllable));
+                }
+            }
+        }
+        ans.sort_by_key(|&(next, _)| std::cmp::Reverse(next));
+        ans
+    }
+
+    /// Where the syllable after one ending at the token `end` begins, skipping the apostrophe in between.
+    /// `None` if the apostrophe is not allowed there.
+    fn next_syllable_at(&mut self, end: usize) -> Option<usize> {
+        if !matches!(self.it.vec.get(end), Some(Ok(PinyinToken::Apostrophe))) {
+            return Some(end);
+        }
+        // in strict mode, `a`, `e` or `o` must follow the apostrophe
+        self.it.next_pos = end + 1;
+        (!self.configs.p_strict.is_strict() || self.it.a_e_o_follows(0)).then_some(end + 1)
+    }
+}
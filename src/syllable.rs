@@ -0,0 +1,183 @@
+use crate::{finals, NonRhoticFinal, SpellingInitial, Tone};
+
+/// A syllable, as parsed by [`crate::PinyinParser`].
+/// Its `Display` gives the same string as [`crate::PinyinParserIter`] does.
+/// ```
+/// use pinyin_parser::{NonRhoticFinal, PinyinParser, SpellingInitial, Syllable, Tone};
+/// let syllables = PinyinParser::new()
+///     .try_parse_syllables("Diǎnr")
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// assert_eq!(
+///     syllables,
+///     vec![Syllable {
+///         initial: SpellingInitial::D,
+///         fin: NonRhoticFinal::Ian,
+///         tone: Tone::Third,
+///         erhua: true,
+///         capitalized: true,
+///         uppercase: 0b1,
+///     }]
+/// );
+/// assert_eq!(syllables[0].to_string(), "diǎnr");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Syllable {
+    pub initial: SpellingInitial,
+    pub fin: NonRhoticFinal,
+    pub tone: Tone,
+
+    /// Whether an erhua `r` follows, as in `diǎnr`
+    pub erhua: bool,
+
+    /// Whether the first letter of the syllable was capitalized, the same as the lowest bit of `uppercase`.
+    /// The other scripts, such as [`Syllable::to_wade_giles`], capitalize by this.
+    pub capitalized: bool,
+
+    /// Which letters of the spelling were in uppercase; the `i`-th bit stands for the `i`-th letter.
+    /// [`Syllable::to_string_preserving_capitalization`] capitalizes by this.
+    pub uppercase: u16,
+}
+
+impl Syllable {
+    /// Same as `to_string`, except that the letters in `uppercase` are capitalized.
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let syllables = PinyinParser::new()
+    ///     .try_parse_syllables("Běijīng ẐĀŊ")
+    ///     .map(|s| s.unwrap().to_string_preserving_capitalization())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(syllables, vec!["Běi", "jīng", "ZHĀNG"]);
+    /// ```
+    #[must_use]
+    pub fn to_string_preserving_capitalization(&self) -> String {
+        let mut ans = String::new();
+        let mut i = 0;
+        for c in self.to_string().chars() {
+            // a combining diacritic belongs to the preceding letter
+            if ('\u{300}'..='\u{36f}').contains(&c) {
+                ans.push(c);
+                continue;
+            }
+            if self.uppercase & (1 << i) != 0 {
+                ans.extend(c.to_uppercase());
+            } else {
+                ans.push(c);
+            }
+            i += 1;
+        }
+        ans
+    }
+}
+
+/// How the light tone is written in numbered pinyin
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum NeutralTone {
+    /// `ma5`
+    #[default]
+    Five,
+
+    /// `ma0`
+    Zero,
+
+    /// `ma`
+    NoDigit,
+}
+
+/// How `ü` is written in numbered pinyin
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum UmlautSpelling {
+    /// `lv4`
+    #[default]
+    V,
+
+    /// `lu:4`
+    UColon,
+
+    /// `lü4`
+    Umlaut,
+}
+
+/// Options for [`Syllable::to_numbered`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct NumberedFormat {
+    pub neutral_tone: NeutralTone,
+    pub umlaut: UmlautSpelling,
+}
+
+/// Displays a [`Syllable`] in numbered pinyin, such as `zhong1`. Created by [`Syllable::to_numbered`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Numbered {
+    syllable: Syllable,
+    format: NumberedFormat,
+}
+
+impl std::fmt::Display for Numbered {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let Syllable {
+            initial,
+            fin,
+            tone,
+            erhua,
+            ..
+        } = self.syllable;
+        let fin = finals::FinalWithTone {
+            fin,
+            tone: Tone::Fifth,
+        }
+        .to_string();
+        let fin = match self.format.umlaut {
+            UmlautSpelling::V => fin.replace('ü', "v"),
+            UmlautSpelling::UColon => fin.replace('ü', "u:"),
+            UmlautSpelling::Umlaut => fin,
+        };
+        let digit = match (tone, self.format.neutral_tone) {
+            (Tone::First, _) => "1",
+            (Tone::Second, _) => "2",
+            (Tone::Third, _) => "3",
+            (Tone::Fourth, _) => "4",
+            (Tone::Fifth, NeutralTone::Five) => "5",
+            (Tone::Fifth, NeutralTone::Zero) => "0",
+            (Tone::Fifth, NeutralTone::NoDigit) => "",
+        };
+        write!(f, "{initial}{fin}{}{digit}", if erhua { "r" } else { "" })
+    }
+}
+
+impl Syllable {
+    /// Renders the syllable in numbered pinyin; the digit comes after the erhua `r`.
+    /// ```
+    /// use pinyin_parser::{NeutralTone, NumberedFormat, PinyinParser, UmlautSpelling};
+    /// let format = NumberedFormat {
+    ///     neutral_tone: NeutralTone::NoDigit,
+    ///     umlaut: UmlautSpelling::UColon,
+    /// };
+    /// let numbered = PinyinParser::new()
+    ///     .try_parse_syllables("Lǜ xiǎojie yīdiǎnr")
+    ///     .map(|s| s.unwrap().to_numbered(format).to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(numbered, vec!["lu:4", "xiao3", "jie", "yi1", "dianr3"]);
+    /// ```
+    #[must_use]
+    pub const fn to_numbered(self, format: NumberedFormat) -> Numbered {
+        Numbered {
+            syllable: self,
+            format,
+        }
+    }
+}
+
+impl std::fmt::Display for Syllable {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            self.initial,
+            finals::FinalWithTone {
+                fin: self.fin,
+                tone: self.tone
+            },
+            if self.erhua { "r" } else { "" }
+        )
+    }
+}
@@ -1,4 +1,9 @@
-use crate::PinyinParser;
+use crate::{
+    is_valid_syllable, syllables, Final, Initial, IpaToneStyle, KatakanaScheme, LossyItem,
+    NeutralTone, NonRhoticFinal, NumberedFormat, ParseError, PinyinParser, Romanization,
+    RomanizationTable, Span, SpellingInitial, Strictness, Syllable, TableError, Token, Tone,
+    ToneNumberStyle, UmlautSpelling, UnderlyingSyllable,
+};
 #[test]
 fn test_strict1() {
     assert_eq!(
@@ -42,7 +47,7 @@ fn test_strict6() {
 #[test]
 fn test() {
     let parser = PinyinParser::new()
-        .with_strictness(crate::Strictness::Strict)
+        .with_strictness(Strictness::Strict)
         .preserve_miscellaneous(true)
         .preserve_spaces(true);
     assert_eq!(
@@ -54,7 +59,7 @@ fn test() {
 #[test]
 fn test2() {
     let parser = PinyinParser::new()
-        .with_strictness(crate::Strictness::Strict)
+        .with_strictness(Strictness::Strict)
         .preserve_miscellaneous(true)
         .preserve_spaces(true);
     assert_eq!(
@@ -91,19 +96,246 @@ fn test5() {
 
 #[test]
 fn test6() {
-    let parser = PinyinParser::new()
-        .with_strictness(crate::Strictness::StrictAndSeparateApostropheFromCurlyQuote);
+    let parser =
+        PinyinParser::new().with_strictness(Strictness::StrictAndSeparateApostropheFromCurlyQuote);
     assert_eq!(
         parser.parse("‘Zhěnglǐ fángjiān’").collect::<Vec<_>>(),
         vec!["zhěng", "lǐ", "fáng", "jiān"]
     );
 }
 
-/*
+#[test]
+fn test_try_parse_lookalike() {
+    let parser = PinyinParser::new().with_strictness(Strictness::Strict);
+    assert_eq!(
+        parser.try_parse("zǒnɡshì").collect::<Vec<_>>(),
+        vec![Err(ParseError::LookalikeCharacter {
+            found: '\u{0261}',
+            looks_like: 'g',
+            span: Span {
+                bytes: 4..6,
+                graphemes: 3..4
+            }
+        })]
+    );
+}
+
+#[test]
+fn test_try_parse_end_after_initial() {
+    assert_eq!(
+        PinyinParser::new().try_parse("nǐ zh").collect::<Vec<_>>(),
+        vec![
+            Ok("nǐ".to_owned()),
+            Err(ParseError::UnexpectedEndAfterInitial {
+                initial: "zh".to_owned(),
+                span: Span {
+                    bytes: 4..6,
+                    graphemes: 3..5
+                }
+            })
+        ]
+    );
+}
+
+#[test]
+fn test_try_parse_no_candidate() {
+    assert!(matches!(
+        PinyinParser::new().try_parse("bx").next(),
+        Some(Err(ParseError::NoCandidateForFinal { .. }))
+    ));
+    assert_eq!(
+        PinyinParser::new().try_parse("'a").collect::<Vec<_>>(),
+        vec![Err(ParseError::UnexpectedApostrophe {
+            span: Span {
+                bytes: 0..1,
+                graphemes: 0..1
+            }
+        })]
+    );
+}
+
+#[test]
+fn test_spans() {
+    let s = "Yīdiǎnr! mián'ǎo";
+    let parser = PinyinParser::new().preserve_punctuations(true);
+    let spanned = parser
+        .parse_with_spans(s)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        spanned
+            .iter()
+            .map(|(range, _)| &s[range.clone()])
+            .collect::<Vec<_>>(),
+        vec!["Yī", "diǎnr", "!", "mián", "ǎo"]
+    );
+}
+
+#[test]
+fn test_error_span() {
+    let s = "hǎo Yīng'guó";
+    let parser = PinyinParser::new().with_strictness(Strictness::Strict);
+    let err = parser.try_parse(s).find_map(Result::err).unwrap();
+    assert_eq!(&s[err.span().bytes.clone()], "'");
+    assert_eq!(err.span().graphemes, 8..9);
+}
+
+fn lossy_texts(parser: PinyinParser, s: &str) -> Vec<Result<String, String>> {
+    parser
+        .parse_lossy(s)
+        .map(|item| match item {
+            LossyItem::Parsed(s) => Ok(s),
+            LossyItem::Invalid { text, .. } => Err(text),
+        })
+        .collect()
+}
+
+/// Parses `s` and writes each syllable out with `render`
+fn rendered<T: std::fmt::Display>(s: &str, render: impl Fn(Syllable) -> T) -> Vec<String> {
+    PinyinParser::new()
+        .try_parse_syllables(s)
+        .map(|syllable| render(syllable.unwrap()).to_string())
+        .collect()
+}
+
+/// Checks that `render` writes every syllable of the inventory out as something
+fn assert_renders_every_syllable<T: std::fmt::Display>(render: impl Fn(Syllable) -> T) {
+    for syllable in syllables() {
+        assert!(!render(syllable).to_string().is_empty(), "{syllable}");
+    }
+}
+
+/// Checks that every syllable of the inventory, written out with `render`, is read back as itself by `parser`
+fn assert_round_trip<T: std::fmt::Display>(parser: PinyinParser, render: impl Fn(Syllable) -> T) {
+    for syllable in syllables() {
+        let written = render(syllable).to_string();
+        assert_eq!(
+            parser.try_parse_syllables(&written).collect::<Vec<_>>(),
+            vec![Ok(syllable)],
+            "{written}"
+        );
+    }
+}
+
+#[test]
+fn test_lossy() {
+    let parser = PinyinParser::new().with_strictness(Strictness::Strict);
+    assert_eq!(
+        lossy_texts(parser, "zǒnɡshì hǎo"),
+        vec![Err("zǒnɡshì".to_owned()), Ok("hǎo".to_owned())]
+    );
+    assert_eq!(
+        lossy_texts(parser, "Yīng'guó"),
+        vec![
+            Ok("yīng".to_owned()),
+            Err("'".to_owned()),
+            Ok("guó".to_owned())
+        ]
+    );
+    assert_eq!(
+        lossy_texts(parser, "xyzBěijīng zh!"),
+        vec![
+            Err("xyz".to_owned()),
+            Ok("běi".to_owned()),
+            Ok("jīng".to_owned()),
+            Err("zh".to_owned())
+        ]
+    );
+}
+
+#[test]
+fn test_syllables() {
+    let syllables = PinyinParser::new()
+        .try_parse_syllables("Ǹ, ài'guó!")
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        syllables,
+        vec![
+            Syllable {
+                initial: SpellingInitial::ZeroAEO,
+                fin: NonRhoticFinal::N,
+                tone: Tone::Fourth,
+                erhua: false,
+                capitalized: true,
+                uppercase: 0b1
+            },
+            Syllable {
+                initial: SpellingInitial::ZeroAEO,
+                fin: NonRhoticFinal::Ai,
+                tone: Tone::Fourth,
+                erhua: false,
+                capitalized: false,
+                uppercase: 0
+            },
+            Syllable {
+                initial: SpellingInitial::G,
+                fin: NonRhoticFinal::Uo,
+                tone: Tone::Second,
+                erhua: false,
+                capitalized: false,
+                uppercase: 0
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_syllables_display_matches_strings() {
+    let s = "yīdiǎnr chànggēr shuāng'ěr Ẑāŋ ḿ lǜ";
+    assert_eq!(
+        PinyinParser::new()
+            .try_parse_syllables(s)
+            .map(|syllable| syllable.unwrap().to_string())
+            .collect::<Vec<_>>(),
+        PinyinParser::loose(s).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_tokens() {
+    let tokens = PinyinParser::new()
+        .with_strictness(Strictness::Strict)
+        .try_parse_tokens("mián'ǎo ·de 好")
+        .map(|token| match token.unwrap() {
+            Token::Syllable(s) => format!("syllable {s}"),
+            other => format!("{other:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tokens,
+        vec![
+            "syllable mián",
+            "Apostrophe",
+            "syllable ǎo",
+            "Space(\" \")",
+            "LightToneMarker",
+            "syllable de",
+            "Space(\" \")",
+            "Other(\"好\")"
+        ]
+    );
+}
+
+#[test]
+fn test_preserve_capitalization() {
+    let parser = PinyinParser::new().preserve_capitalization(true);
+    assert_eq!(
+        parser.parse("Běijīng BĚIJĪNG Ẑāŋ Ēr").collect::<Vec<_>>(),
+        vec!["Běi", "jīng", "BĚI", "JĪNG", "Zhāng", "Ēr"]
+    );
+    assert_eq!(
+        parser.parse("YĪDIǍNR").split_erhua().collect::<Vec<_>>(),
+        vec!["YĪ", "DIǍN", "R"]
+    );
+}
+
 #[test]
 fn test_loose2() {
     assert_eq!(
-        PinyinParser::loose("ni3 hao3")
+        PinyinParser::new()
+            .accept_tone_numbers(true)
+            .parse("ni3 hao3")
             .collect::<Vec<_>>(),
         vec!["nǐ", "hǎo"]
     );
@@ -112,10 +344,910 @@ fn test_loose2() {
 #[test]
 fn test_loose3() {
     assert_eq!(
-        PinyinParser::loose("mi2ngtian1")
+        PinyinParser::new()
+            .accept_tone_numbers(true)
+            .parse("mi2ngtian1")
             .collect::<Vec<_>>(),
         vec!["míng", "tiān"]
     );
 }
 
-*/
+#[test]
+fn test_tone_numbers() {
+    let parser = PinyinParser::new().accept_tone_numbers(true);
+    assert_eq!(
+        parser
+            .parse("lv4 liu2 gui4 dou1 er2 xiong2 m2 de0 hua4r5")
+            .collect::<Vec<_>>(),
+        vec!["lǜ", "liú", "guì", "dōu", "ér", "xióng", "ḿ", "de", "huàr"]
+    );
+
+    // a digit marks the end of a syllable
+    assert_eq!(
+        parser.parse("fang1an4 fan1gan4").collect::<Vec<_>>(),
+        vec!["fāng", "àn", "fān", "gàn"]
+    );
+
+    // CC-CEDICT writes `ü` as `u:`
+    assert_eq!(
+        parser.parse("Lu:4 nu:3 lu:e4").collect::<Vec<_>>(),
+        vec!["lǜ", "nǚ", "lüè"]
+    );
+
+    // a digit that no letter before it can bear
+    for s in ["x4", "hǎo3"] {
+        assert!(matches!(
+            parser.try_parse(s).last(),
+            Some(Err(ParseError::MisplacedToneNumber { .. }))
+        ));
+    }
+    assert_eq!(
+        parser
+            .with_strictness(Strictness::Strict)
+            .parse("xi1an1")
+            .collect::<Vec<_>>(),
+        vec!["xī", "ān"]
+    );
+    let s = "Xi1'an1 hao3";
+    let spanned = parser
+        .parse_with_spans(s)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        spanned
+            .iter()
+            .map(|(range, _)| &s[range.clone()])
+            .collect::<Vec<_>>(),
+        vec!["Xi1", "an1", "hao3"]
+    );
+    assert_eq!(
+        PinyinParser::new()
+            .preserve_miscellaneous(true)
+            .parse("hao3")
+            .collect::<Vec<_>>(),
+        vec!["hao", "3"]
+    );
+}
+
+#[test]
+fn test_numbered() {
+    let numbered = |format| rendered("Zhōngguó nǚ'ér lüè ma ḿ", |s| s.to_numbered(format));
+    assert_eq!(
+        numbered(NumberedFormat::default()),
+        vec!["zhong1", "guo2", "nv3", "er2", "lve4", "ma5", "m2"]
+    );
+    assert_eq!(
+        numbered(NumberedFormat {
+            neutral_tone: NeutralTone::Zero,
+            umlaut: UmlautSpelling::Umlaut
+        }),
+        vec!["zhong1", "guo2", "nü3", "er2", "lüe4", "ma0", "m2"]
+    );
+
+    let parser = PinyinParser::new().accept_tone_numbers(true);
+    for umlaut in [
+        UmlautSpelling::V,
+        UmlautSpelling::UColon,
+        UmlautSpelling::Umlaut,
+    ] {
+        let format = NumberedFormat {
+            umlaut,
+            ..NumberedFormat::default()
+        };
+        assert_round_trip(parser, |s| s.to_numbered(format));
+    }
+}
+
+#[test]
+fn test_zhuyin() {
+    let zhuyin = |s| rendered(s, Syllable::to_zhuyin);
+    assert_eq!(
+        zhuyin("yī yǒu wǒ yuè yòng wēng lǜ jūn xióng"),
+        vec![
+            "ㄧ",
+            "ㄧㄡˇ",
+            "ㄨㄛˇ",
+            "ㄩㄝˋ",
+            "ㄩㄥˋ",
+            "ㄨㄥ",
+            "ㄌㄩˋ",
+            "ㄐㄩㄣ",
+            "ㄒㄩㄥˊ"
+        ]
+    );
+    assert_eq!(
+        zhuyin("zhī chī shī rì zì cí sì"),
+        vec!["ㄓ", "ㄔ", "ㄕ", "ㄖˋ", "ㄗˋ", "ㄘˊ", "ㄙˋ"]
+    );
+    assert_eq!(
+        zhuyin("ér huār liǎngr ma"),
+        vec!["ㄦˊ", "ㄏㄨㄚㄦ", "ㄌㄧㄤˇㄦ", "˙ㄇㄚ"]
+    );
+    let ê = Syllable {
+        initial: SpellingInitial::ZeroAEO,
+        fin: NonRhoticFinal::Ê,
+        tone: Tone::Fourth,
+        erhua: false,
+        capitalized: false,
+        uppercase: 0,
+    };
+    assert_eq!(ê.to_zhuyin().to_string(), "ㄝˋ");
+    assert_eq!(
+        PinyinParser::new()
+            .try_parse_syllables("zhōng")
+            .map(|s| s.unwrap().to_zhuyin().mark_first_tone(true).to_string())
+            .collect::<Vec<_>>(),
+        vec!["ㄓㄨㄥˉ"]
+    );
+}
+
+#[test]
+fn test_zhuyin_input() {
+    let parser = PinyinParser::new().accept_zhuyin(true);
+    assert_round_trip(parser, Syllable::to_zhuyin);
+    assert!(matches!(
+        parser.try_parse("ㄅ").next(),
+        Some(Err(ParseError::InvalidZhuyin { found, .. })) if found == "ㄅ"
+    ));
+    assert!(matches!(
+        parser.try_parse_syllables("hǎo ㄅㄧㄞ").nth(1),
+        Some(Err(ParseError::InvalidZhuyin { found, .. })) if found == "ㄅㄧㄞ"
+    ));
+    assert_eq!(
+        parser
+            .parse("ㄓㄨㄥˉㄨㄣˊ ㄒㄧ ㄢ ㄩㄝˋ ㄌㄩˋ ㄐㄩㄣ ㄕˋ ㄦˊ ㄏㄨㄚㄦ ˙ㄇㄚ")
+            .collect::<Vec<_>>(),
+        vec!["zhōng", "wén", "xī", "ān", "yuè", "lǜ", "jūn", "shì", "ér", "huār", "ma"]
+    );
+
+    // pinyin writes a light-tone `ü` or `ê` without a mark
+    assert_eq!(
+        parser.parse("˙ㄋㄩ ˙ㄝ ˙ㄌㄩㄝ").collect::<Vec<_>>(),
+        vec!["nü", "ê", "lüe"]
+    );
+    assert_eq!(
+        parser
+            .try_parse_syllables("ㄒㄧˊ")
+            .collect::<Result<Vec<_>, _>>(),
+        PinyinParser::new()
+            .try_parse_syllables("xí")
+            .collect::<Result<Vec<_>, _>>()
+    );
+    let s = "ㄋㄧˇ hǎo";
+    let spanned = parser
+        .parse_with_spans(s)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        spanned
+            .iter()
+            .map(|(range, _)| &s[range.clone()])
+            .collect::<Vec<_>>(),
+        vec!["ㄋㄧˇ", "hǎo"]
+    );
+    let pinyin = "yīdiǎnr Yǒngyuǎn wēng wàiguó nǚ'ér lüè qióng zhīdao ḿ";
+    let zhuyin = rendered(pinyin, Syllable::to_zhuyin).concat();
+    assert_eq!(
+        parser.parse(&zhuyin).collect::<Vec<_>>(),
+        PinyinParser::loose(pinyin).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_wade_giles() {
+    let wade_giles = |s| rendered(s, |s| s.to_wade_giles(ToneNumberStyle::Trailing));
+    assert_eq!(
+        wade_giles("zhī chī shī rì zì cí sì"),
+        vec!["chih1", "ch'ih1", "shih1", "jih4", "tzŭ4", "tz'ŭ2", "ssŭ4"]
+    );
+    assert_eq!(
+        wade_giles("bā pà dé tè gē kě hé jiā qù xuě lǜ nüè"),
+        vec![
+            "pa1", "p'a4", "tê2", "t'ê4", "ko1", "k'o3", "ho2", "chia1", "ch'ü4", "hsüeh3", "lü4",
+            "nüeh4"
+        ]
+    );
+    assert_eq!(
+        wade_giles("yī yǒu yán yuè yòng wǒ wèn duō guó guì ér huār de"),
+        vec![
+            "i1", "yu3", "yen2", "yüeh4", "yung4", "wo3", "wên4", "to1", "kuo2", "kuei4", "êrh2",
+            "huarh1", "tê"
+        ]
+    );
+    assert_eq!(
+        PinyinParser::new()
+            .try_to_wade_giles("Máo Zédōng, Xī'ān!", ToneNumberStyle::Superscript)
+            .unwrap(),
+        "Mao² Tsê²-tung¹, Hsi¹-an¹!"
+    );
+}
+
+#[test]
+fn test_wade_giles_input() {
+    let parser = PinyinParser::new().accept_wade_giles(true);
+    assert_eq!(
+        parser
+            .parse("Mao Tse-tung, Ch'ing-tao, Chiang Chieh-shih, Hsi-an, tzŭ, ssu, shih, jih, yü, yu, kuo, k’o, lo, êrh, hsüeh")
+            .collect::<Vec<_>>(),
+        vec![
+            "mao", "ze", "dong", "qing", "dao", "jiang", "jie", "shi", "xi", "an", "zi", "si", "shi",
+            "ri", "yu", "you", "guo", "ke", "luo", "er", "xue"
+        ]
+    );
+    assert_eq!(
+        parser.parse("Chung¹-kuo² Pei3ching1").collect::<Vec<_>>(),
+        vec!["zhōng", "guó", "běi", "jīng"]
+    );
+    assert_eq!(
+        parser
+            .parse("yai² ch'uangrh⁴ huarh m² n⁴")
+            .collect::<Vec<_>>(),
+        vec!["yái", "chuàngr", "huar", "ḿ", "ǹ"]
+    );
+    assert_eq!(
+        parser.try_parse_syllables("hao3 xq").nth(1),
+        Some(Err(ParseError::InvalidWadeGiles {
+            found: "xq".to_owned(),
+            span: Span {
+                bytes: 5..7,
+                graphemes: 5..7,
+            },
+        }))
+    );
+
+    // a syllable without an initial begins a word, so `hsian` is neither `hsi-an` nor `hsia-n`
+    assert!(matches!(
+        parser.try_parse("hsian").next(),
+        Some(Err(ParseError::InvalidWadeGiles { found, .. })) if found == "hsian"
+    ));
+
+    // the curly quote marks the aspiration even when it is kept apart from the apostrophe
+    assert_eq!(
+        parser
+            .with_strictness(Strictness::StrictAndSeparateApostropheFromCurlyQuote)
+            .parse("Ch’ing-tao")
+            .collect::<Vec<_>>(),
+        vec!["qing", "dao"]
+    );
+    assert_eq!(
+        parser.try_to_wade_giles("Chung¹-kuo²", ToneNumberStyle::Superscript),
+        Ok("Chung¹-kuo²".to_owned())
+    );
+    let ambiguities = PinyinParser::new().wade_giles_ambiguities("Chu Teh, o, lo");
+    assert_eq!(
+        ambiguities
+            .into_iter()
+            .map(|a| (a.span.bytes, a.readings))
+            .collect::<Vec<_>>(),
+        vec![
+            (0..3, vec!["zhu".to_owned(), "ju".to_owned()]),
+            (9..10, vec!["e".to_owned(), "o".to_owned()]),
+            (12..14, vec!["luo".to_owned(), "lo".to_owned()])
+        ]
+    );
+
+    // every syllable comes back, unless Wade–Giles spells it like another one
+    for syllable in syllables() {
+        let wade_giles = syllable
+            .to_wade_giles(ToneNumberStyle::Superscript)
+            .to_string();
+        let toneless = Syllable {
+            tone: Tone::Fifth,
+            ..syllable
+        }
+        .to_string();
+        let read = parser.try_parse_syllables(&wade_giles).collect::<Vec<_>>();
+        assert!(
+            read == vec![Ok(syllable)]
+                || PinyinParser::new()
+                    .wade_giles_ambiguities(&wade_giles)
+                    .iter()
+                    .any(|a| a.readings.contains(&toneless)),
+            "{wade_giles}"
+        );
+    }
+}
+
+#[test]
+fn test_gwoyeu_romatzyh() {
+    let gr = |s| rendered(s, Syllable::to_gwoyeu_romatzyh);
+    assert_eq!(
+        gr("yī yí yǐ yì wū wú wǔ wù yú yǔ yù"),
+        vec!["i", "yi", "yii", "yih", "u", "wu", "wuu", "wuh", "yu", "yeu", "yuh"]
+    );
+    assert_eq!(
+        gr("lái bái mǎi hǎo tóu dōng hóng ér ěr èr"),
+        vec!["lai", "bair", "mae", "hao", "tour", "dong", "horng", "erl", "eel", "ell"]
+    );
+    assert_eq!(
+        gr("jiě jiǎ xiǎo jiǔ diǎn jǐng xuě xuǎn guǎn guǐ nǚ yě yǒu"),
+        vec![
+            "jiee", "jea", "sheau", "jeou", "dean", "jiing", "sheue", "sheuan", "goan", "goei",
+            "neu", "yee", "yeou"
+        ]
+    );
+    assert_eq!(
+        gr("qí píng xué quán huí zhí rì zì jiào jiàn dìng kuài lǜ yào wàng liū rēng"),
+        vec![
+            "chyi", "pyng", "shyue", "chyuan", "hwei", "jyr", "ryh", "tzyh", "jiaw", "jiann",
+            "dinq", "kuay", "liuh", "yaw", "wanq", "lhiou", "rheng"
+        ]
+    );
+    assert_eq!(
+        gr("wēng wéng wěng wèng ê̄ ế ê̌ ề ê"),
+        vec!["ueng", "weng", "woeng", "wenq", "è", "èr", "èè", "èh", "·ê"]
+    );
+    assert_renders_every_syllable(Syllable::to_gwoyeu_romatzyh);
+}
+
+#[test]
+fn test_yale_mps2() {
+    let yale = |s| rendered(s, Syllable::to_yale);
+    let mps2 = |s| rendered(s, Syllable::to_mps2);
+    assert_eq!(yale("qù"), vec!["chyù"]);
+    assert_eq!(mps2("qù"), vec!["chiù"]);
+    assert_eq!(
+        yale("bō duì kūn tiān liù nǚ yuè xióng yī wǔ wén ér huār cì rì"),
+        vec![
+            "bwō", "dwèi", "kwūn", "tyān", "lyòu", "nyǔ", "ywè", "syúng", "yī", "wǔ", "wén", "ér",
+            "hwār", "tsz̀", "r̀"
+        ]
+    );
+    assert_eq!(
+        mps2("bō duì kūn tiān liù nǚ yuè xióng yī wǔ wén ér huār cì rì yán"),
+        vec![
+            "bō", "duèi", "kuēn", "tiēn", "liòu", "niǔ", "yuè", "shiúng", "yī", "wǔ", "wén", "ér",
+            "huār", "tsz̀", "r̀", "yán"
+        ]
+    );
+    assert_renders_every_syllable(Syllable::to_yale);
+    assert_renders_every_syllable(Syllable::to_mps2);
+}
+
+#[test]
+fn test_tongyong() {
+    let tongyong = |s| rendered(s, Syllable::to_tongyong);
+    assert_eq!(
+        tongyong("zhī chī shī rì zī cí sì jī qí xǐ jù qū xū nǚ lüè"),
+        vec![
+            "jhih", "chih", "shih", "rìh", "zih", "cíh", "sìh", "ji", "cí", "sǐ", "jyù", "cyu",
+            "syu", "nyǔ", "lyuè"
+        ]
+    );
+    assert_eq!(
+        tongyong("liú duì lún wēng wén xióng bèng mēng huār ér ḿ"),
+        vec![
+            "lióu", "duèi", "lún", "wong", "wún", "syóng", "bòng", "mong", "huar", "ér", "ḿ"
+        ]
+    );
+
+    // every syllable comes back the same through Tongyong Pinyin, even in strict mode
+    let pinyin = "zhōng guó xīn yì cì xù jiǒng quán yuè wǔ ér huār niáng lüè fēng \
+                  biān diū guǐ kūn ěr shì zi sī rēng yō";
+    let through_tongyong = tongyong(pinyin).join(" ");
+    let parser = PinyinParser::new()
+        .with_strictness(Strictness::Strict)
+        .accept_tongyong(true);
+    assert_eq!(
+        parser.parse(&through_tongyong).collect::<Vec<_>>(),
+        PinyinParser::new().parse(pinyin).collect::<Vec<_>>()
+    );
+
+    // the spellings often seen in place of Tongyong Pinyin are only accepted in loose mode
+    let loose = PinyinParser::new().accept_tongyong(true);
+    assert_eq!(
+        loose.parse("jhi jyu ju liu gui wen").collect::<Vec<_>>(),
+        vec!["zhī", "jū", "jū", "liū", "guī", "wēn"]
+    );
+    assert_eq!(
+        parser.parse("jhih jyu liou guei wun").collect::<Vec<_>>(),
+        vec!["zhī", "jū", "liū", "guī", "wēn"]
+    );
+    assert!(parser.try_parse("jhi").any(|s| s.is_err()));
+
+    let syllable = parser.try_parse_syllables("Jhong").next().unwrap().unwrap();
+    assert!(syllable.capitalized);
+    assert_eq!(syllable.uppercase, 0b1);
+
+    // Hanyu Pinyin is not read in its place
+    for s in ["zhong", "xi", "zhi"] {
+        assert!(
+            matches!(
+                parser.try_parse(s).next(),
+                Some(Err(ParseError::InvalidTongyong { found, .. })) if found == s
+            ),
+            "{s}"
+        );
+    }
+    assert_eq!(loose.parse("de̊").collect::<Vec<_>>(), vec!["de"]);
+    assert_eq!(tongyong("yāi ê"), vec!["yai", "ê̊"]);
+    assert_eq!(parser.parse("yai ê̊").collect::<Vec<_>>(), vec!["yāi", "ê"]);
+
+    // and so does every syllable of the inventory
+    assert_round_trip(parser, Syllable::to_tongyong);
+    assert_round_trip(loose, Syllable::to_tongyong);
+}
+
+#[test]
+fn test_ipa() {
+    let ipa = |s, style| rendered(s, |s| s.to_ipa(style));
+    assert_eq!(
+        ipa(
+            "zī cí shì rì bō gē yè yuè ài dāng tiān yán wèng qióng",
+            IpaToneStyle::ChaoLetters
+        ),
+        vec![
+            "tsɹ̩˥",
+            "tsʰɹ̩˧˥",
+            "ʂɻ̩˥˩",
+            "ʐɻ̩˥˩",
+            "pwo˥",
+            "kɤ˥",
+            "jɛ˥˩",
+            "ɥɛ˥˩",
+            "ai˥˩",
+            "tɑŋ˥",
+            "tʰjɛn˥",
+            "jɛn˧˥",
+            "wəŋ˥˩",
+            "tɕʰjʊŋ˧˥"
+        ]
+    );
+    assert_eq!(
+        ipa("mā má mǎ mà ma", IpaToneStyle::Digits),
+        vec!["ma⁵⁵", "ma³⁵", "ma²¹⁴", "ma⁵¹", "ma"]
+    );
+    assert_eq!(
+        ipa(
+            "ér yīdiǎnr xiǎoháir zìr jīr yìdiǎnr xìngr wánr gēr",
+            IpaToneStyle::ChaoLetters
+        ),
+        vec![
+            "ɚ˧˥",
+            "i˥",
+            "tjaɻ˨˩˦",
+            "ɕjɑu˨˩˦",
+            "xaɻ˧˥",
+            "tsəɻ˥˩",
+            "tɕjəɻ˥",
+            "i˥˩",
+            "tjaɻ˨˩˦",
+            "ɕjə̃ɻ˥˩",
+            "waɻ˧˥",
+            "kɤɻ˥"
+        ]
+    );
+    assert_renders_every_syllable(|s| s.to_ipa(IpaToneStyle::ChaoLetters));
+    assert_renders_every_syllable(|s| s.to_ipa(IpaToneStyle::Digits));
+}
+
+#[test]
+fn test_palladius() {
+    let palladius = |s| rendered(s, Syllable::to_palladius);
+    assert_eq!(
+        palladius("zhī cí rì jiā qiū xué nǚ guó shuō wǒ wèi wēng yǒng lǜ ér huār"),
+        vec![
+            "чжи", "цы", "жи", "цзя", "цю", "сюэ", "нюй", "го", "шо", "во", "вэй", "вэн", "юн",
+            "люй", "эр", "хуар"
+        ]
+    );
+    assert_eq!(
+        PinyinParser::new()
+            .try_to_palladius("Xī'ān, Tiān'ānmén, Píng'ān - hǎo")
+            .unwrap(),
+        "Сиань, Тяньаньмэнь, Пинъань - хао"
+    );
+    assert_renders_every_syllable(Syllable::to_palladius);
+}
+
+#[test]
+fn test_hangul() {
+    let hangul = |s| rendered(s, Syllable::to_hangul);
+    assert_eq!(
+        hangul("zhī chī shī rì zī cí sì rén ròu zhōu"),
+        vec!["즈", "츠", "스", "르", "쯔", "츠", "쓰", "런", "러우", "저우"]
+    );
+    assert_eq!(
+        hangul("jiā jiě jiǔ qiáo qióng jiàn xiè xiǎo xiù jué xuǎn jūn lǜ"),
+        vec![
+            "자", "제", "주", "차오", "충", "젠", "셰", "샤오", "슈", "줴", "쉬안", "쥔", "뤼"
+        ]
+    );
+    assert_eq!(
+        hangul("tiān liú duō guì dūn wèi wén wēng yǒng yuè ér nǎr diǎnr"),
+        vec![
+            "톈", "류", "둬", "구이", "둔", "웨이", "원", "웡", "융", "웨", "얼", "날", "뎬얼"
+        ]
+    );
+    assert_renders_every_syllable(Syllable::to_hangul);
+}
+
+#[test]
+fn test_katakana() {
+    let katakana = |s| rendered(s, |s| s.to_katakana(KatakanaScheme::Phonetic));
+    assert_eq!(
+        katakana("zhī chī shí rì zì cí sì fēng rén liú xiè jiāng tiān nǚ xué quán yún"),
+        vec![
+            "ジ",
+            "チ",
+            "シ",
+            "リ",
+            "ズ",
+            "ツ",
+            "ス",
+            "フォン",
+            "レン",
+            "リョウ",
+            "シェ",
+            "ジャン",
+            "ティェン",
+            "ニュイ",
+            "シュエ",
+            "チュアン",
+            "ユン"
+        ]
+    );
+    assert_eq!(
+        katakana("yī yā yě yào yǒu wǔ wǒ wài wèi wàn wén huā guǎi duì kùn gē ér huār"),
+        vec![
+            "イ",
+            "ヤ",
+            "イェ",
+            "ヤオ",
+            "ヨウ",
+            "ウ",
+            "ウォ",
+            "ワイ",
+            "ウェイ",
+            "ワン",
+            "ウェン",
+            "ファ",
+            "グァイ",
+            "ドゥェイ",
+            "クェン",
+            "グー",
+            "アル",
+            "ファル"
+        ]
+    );
+    assert_renders_every_syllable(|s| s.to_katakana(KatakanaScheme::Phonetic));
+}
+
+#[test]
+fn test_romanization_table() {
+    let table = "
+        # tone-specific lines win over the general ones
+        ma1 mha
+        ma  ma
+        ma3 maa
+        nü  nyu
+        er  ell
+        huar hwall
+        hua hwa
+        @erhua l
+        @separator '
+    "
+    .parse::<RomanizationTable>()
+    .unwrap();
+    let parser = PinyinParser::new();
+    assert_eq!(
+        parser.try_romanize("mā má mǎ mà ma", &table).unwrap(),
+        "mha ma maa ma ma"
+    );
+    assert_eq!(
+        parser.try_romanize("Nǚ'ér huār mar, lǜ", &table).unwrap(),
+        "Nyu'ell hwall mal, lǜ"
+    );
+    assert_eq!(parser.try_romanize("Lǜ LǛ mà", &table).unwrap(), "Lǜ LǛ ma");
+    assert_eq!(
+        table.syllable(parser.try_parse_syllables("nǖ").next().unwrap().unwrap()),
+        "nyu"
+    );
+
+    assert_eq!(
+        "zhong\n\nguo".parse::<RomanizationTable>(),
+        Err(TableError::MissingValue { line: 1 })
+    );
+    assert_eq!(
+        "# comment\nzhongguo jongguo".parse::<RomanizationTable>(),
+        Err(TableError::InvalidKey {
+            line: 2,
+            key: "zhongguo".to_owned()
+        })
+    );
+    assert_eq!(
+        "zhong0 jong".parse::<RomanizationTable>(),
+        Err(TableError::InvalidKey {
+            line: 1,
+            key: "zhong0".to_owned()
+        })
+    );
+    assert_eq!(
+        "@joiner -".parse::<RomanizationTable>(),
+        Err(TableError::UnknownDirective {
+            line: 1,
+            directive: "@joiner".to_owned()
+        })
+    );
+}
+
+#[test]
+fn test_invalid_syllables() {
+    let strict = PinyinParser::new().with_strictness(Strictness::Strict);
+    for s in ["bue", "fi", "gü", "zhin", "dia", "jo", "nue"] {
+        assert!(
+            matches!(
+                strict.try_parse(s).next(),
+                Some(Err(ParseError::InvalidSyllable { syllable, .. })) if syllable == s
+            ),
+            "{s}"
+        );
+    }
+    assert!(matches!(
+        strict.try_parse("nǐ hǎo fì").nth(2),
+        Some(Err(ParseError::InvalidSyllable { span, .. })) if span.bytes == (9..12)
+    ));
+    assert_eq!(
+        strict.parse("nüè jué yǒng lüè lo tēi").collect::<Vec<_>>(),
+        vec!["nüè", "jué", "yǒng", "lüè", "lo", "tēi"]
+    );
+
+    // the loose mode accepts them, but reports them on request
+    let loose = PinyinParser::new();
+    assert_eq!(
+        loose.parse("fi gü jo").collect::<Vec<_>>(),
+        vec!["fi", "gü", "jo"]
+    );
+    let invalid = loose.invalid_syllables("Běijīng fi gü");
+    assert_eq!(
+        invalid
+            .iter()
+            .map(|(span, s)| (span.bytes.clone(), s.to_string()))
+            .collect::<Vec<_>>(),
+        vec![(10..12, "fi".to_owned()), (13..16, "gü".to_owned())]
+    );
+
+    // errors of any kind are skipped, and the strict modes list them all the same
+    for parser in [loose, strict] {
+        assert_eq!(
+            parser
+                .invalid_syllables("fi bx, guèi ji'ing hǎo gü")
+                .iter()
+                .map(|(_, s)| s.to_string())
+                .collect::<Vec<_>>(),
+            vec!["fi", "gü"]
+        );
+    }
+    assert_eq!(
+        strict
+            .parse_lossy("fi hǎo")
+            .filter(|item| matches!(item, LossyItem::Parsed(_)))
+            .count(),
+        1
+    );
+
+    assert!(is_valid_syllable(
+        SpellingInitial::ZeroAEO,
+        NonRhoticFinal::E
+    ));
+    assert!(is_valid_syllable(SpellingInitial::W, NonRhoticFinal::Eng));
+    assert!(!is_valid_syllable(SpellingInitial::W, NonRhoticFinal::Ong));
+    assert!(!is_valid_syllable(SpellingInitial::B, NonRhoticFinal::Ue));
+}
+
+#[test]
+fn test_syllable_inventory() {
+    let all = syllables().collect::<Vec<_>>();
+    // 412 syllables in five tones, less the light tone of `m` and `n`, and `er` in five tones
+    assert_eq!(all.len(), 412 * 5 - 2 + 5);
+    assert_eq!(all.iter().filter(|s| s.erhua).count(), 5);
+
+    // every syllable parses back to itself in strict mode, tone numbers included
+    let strict = PinyinParser::new().with_strictness(Strictness::Strict);
+    let text = all
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" ");
+    assert_eq!(
+        strict
+            .try_parse_syllables(&text)
+            .collect::<Result<Vec<_>, _>>(),
+        Ok(all.clone())
+    );
+    let numbered = all
+        .iter()
+        .map(|s| s.to_numbered(NumberedFormat::default()).to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    assert_eq!(
+        strict
+            .accept_tone_numbers(true)
+            .try_parse_syllables(&numbered)
+            .collect::<Result<Vec<_>, _>>(),
+        Ok(all.clone())
+    );
+
+    // and the strict parser rejects everything else
+    assert!(all
+        .iter()
+        .all(|s| s.erhua || is_valid_syllable(s.initial, s.fin)));
+    assert!(!all
+        .iter()
+        .any(|s| s.initial == SpellingInitial::F && s.fin == NonRhoticFinal::I));
+    assert!(matches!(
+        strict.try_parse("fi").next(),
+        Some(Err(ParseError::InvalidSyllable { syllable, .. })) if syllable == "fi"
+    ));
+}
+
+#[test]
+fn test_underlying() {
+    let parser = PinyinParser::new();
+    let underlying = parser
+        .try_parse_syllables("yī yǒu wǒ wēng yuè nüè jūn yái ér huār zhī")
+        .map(|s| s.unwrap().to_underlying().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        underlying,
+        vec!["i", "iou", "uo", "ueng", "üe", "nüe", "jün", "iai", "er", "huar", "zhi"]
+    );
+
+    // loose spellings come back in the standard spelling
+    assert_eq!(
+        parser
+            .try_parse_syllables("nuè")
+            .map(|s| s.unwrap().to_underlying().to_syllable().to_string())
+            .collect::<Vec<_>>(),
+        vec!["nüè"]
+    );
+    assert_eq!(
+        UnderlyingSyllable {
+            initial: Initial::Q,
+            fin: Final::Üan,
+            tone: Tone::Second,
+            erhua: false,
+        }
+        .to_syllable()
+        .to_string(),
+        "quán"
+    );
+
+    // every syllable of the inventory survives the round trip
+    for syllable in syllables() {
+        assert_eq!(
+            syllable.to_underlying().to_syllable(),
+            syllable,
+            "{syllable}"
+        );
+    }
+}
+
+#[test]
+fn test_full_form_finals() {
+    let loose = PinyinParser::new();
+    assert_eq!(
+        loose
+            .parse("liòu guèi duēn uēng uèn iǒu ueŋ shuěir jiōu")
+            .collect::<Vec<_>>(),
+        vec!["liù", "guì", "dūn", "wēng", "wèn", "yǒu", "weng", "shuǐr", "jiū"]
+    );
+    assert_eq!(
+        loose
+            .try_parse_syllables("Uēng")
+            .next()
+            .unwrap()
+            .unwrap()
+            .to_string_preserving_capitalization(),
+        "Wēng"
+    );
+
+    // `juen` is not the full form of `jun`, nor is `dueng` of `dong`
+    assert!(loose.try_parse("juen").next().unwrap().is_err());
+    assert!(loose.try_parse("dueng").next().unwrap().is_err());
+
+    let strict = PinyinParser::new().with_strictness(Strictness::Strict);
+    assert_eq!(
+        strict.try_parse("hǎo guèi").nth(1),
+        Some(Err(ParseError::FullFormFinal {
+            full_form: "guèi".to_string(),
+            standard: "guì".to_string(),
+            span: Span {
+                bytes: 5..10,
+                graphemes: 4..8,
+            },
+        }))
+    );
+    assert!(matches!(
+        strict.try_parse("uēng").next(),
+        Some(Err(ParseError::FullFormFinal { full_form, standard, .. }))
+            if full_form == "uēng" && standard == "wēng"
+    ));
+
+    // only the full forms may begin with `i` or `u`
+    for s in ["ing", "u", "ü", "iu", "ui", "un"] {
+        for parser in [loose, strict] {
+            assert!(
+                matches!(
+                    parser.try_parse(s).last(),
+                    Some(Err(ParseError::UnexpectedAlphabetAtWordStart { .. }))
+                ),
+                "{s}"
+            );
+        }
+    }
+    assert!(matches!(
+        loose.try_parse("ji'ing").nth(1),
+        Some(Err(ParseError::UnexpectedAlphabetAtWordStart { .. }))
+    ));
+}
+
+#[test]
+fn test_segmentations() {
+    let strict = PinyinParser::new().with_strictness(Strictness::Strict);
+    let readings = |parser: PinyinParser, word| {
+        parser
+            .segmentations(word)
+            .map(|s| s.iter().map(ToString::to_string).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        readings(strict, "Tiānānmén"),
+        vec![
+            vec!["tiān", "ān", "mén"],
+            vec!["ti", "ān", "ān", "mén"],
+            vec!["ti", "ā", "nān", "mén"],
+        ]
+    );
+    assert_eq!(
+        readings(strict, "huar"),
+        vec![vec!["huar"], vec!["hu", "ar"]]
+    );
+    assert_eq!(readings(strict, "xīān"), vec![vec!["xī", "ān"]]);
+    assert_eq!(readings(strict, "liou"), vec![vec!["li", "ou"]]);
+
+    // the parser's own reading comes first
+    let loose = PinyinParser::new();
+    assert_eq!(
+        readings(loose, "Tiānānmén")[0],
+        loose.parse("Tiānānmén").collect::<Vec<_>>()
+    );
+    assert_eq!(readings(loose, "liou")[0], vec!["liu"]);
+
+    // capitalization is kept on the syllables
+    let segmentations = strict.segmentations("Xian").collect::<Vec<_>>();
+    assert!(segmentations[1][0].capitalized);
+    assert!(!segmentations[1][1].capitalized);
+
+    // apostrophes are read as the parser reads them
+    assert_eq!(readings(strict, "xi’an"), vec![vec!["xi", "an"]]);
+    assert!(readings(
+        strict.with_strictness(Strictness::StrictAndSeparateApostropheFromCurlyQuote),
+        "xi’an"
+    )
+    .is_empty());
+    assert!(readings(strict, "ji'ing").is_empty());
+    assert!(readings(loose, "ji'ing").is_empty());
+
+    // long words
+    let word = "Zhōnghuárénmíngònghéguó";
+    let segmentations = readings(strict, word);
+    assert_eq!(segmentations.len(), 8);
+    assert_eq!(segmentations[0], strict.parse(word).collect::<Vec<_>>());
+    let segmentations = strict.segmentations(&"xian".repeat(12)).collect::<Vec<_>>();
+    assert_eq!(segmentations.len(), 1 << 12);
+    assert!(segmentations
+        .iter()
+        .all(|s| s.iter().map(ToString::to_string).collect::<String>() == "xian".repeat(12)));
+
+    // the readings are found one at a time, even when there are too many to list
+    let word = "xian".repeat(200);
+    let mut segmentations = strict.segmentations(&word);
+    assert_eq!(segmentations.next().unwrap().len(), 200);
+    let reading = segmentations.nth(1000).unwrap();
+    assert!(reading.len() > 200);
+    assert_eq!(
+        reading.iter().map(ToString::to_string).collect::<String>(),
+        word
+    );
+}
@@ -0,0 +1,176 @@
+use crate::pinyin_token::{Alphabet, AlphabetWithDiacritics, Diacritic, PinyinToken};
+use crate::{ParseError, Tone, VecAndIndex};
+
+const fn tone_of_number(s: &str) -> Option<Tone> {
+    match s.as_bytes() {
+        b"1" => Some(Tone::First),
+        b"2" => Some(Tone::Second),
+        b"3" => Some(Tone::Third),
+        b"4" => Some(Tone::Fourth),
+        b"5" | b"0" => Some(Tone::Fifth),
+        _ => None,
+    }
+}
+
+const fn diacritic_of_tone(tone: Tone) -> Option<Diacritic> {
+    match tone {
+        Tone::First => Some(Diacritic::Macron),
+        Tone::Second => Some(Diacritic::Acute),
+        Tone::Third => Some(Diacritic::Hacek),
+        Tone::Fourth => Some(Diacritic::Grave),
+        Tone::Fifth => None,
+    }
+}
+
+const fn is_tone(d: &Diacritic) -> bool {
+    matches!(
+        d,
+        Diacritic::Macron
+            | Diacritic::Acute
+            | Diacritic::Hacek
+            | Diacritic::Breve
+            | Diacritic::Grave
+    )
+}
+
+const fn is_vowel(a: Alphabet) -> bool {
+    matches!(
+        a,
+        Alphabet::A | Alphabet::E | Alphabet::I | Alphabet::O | Alphabet::U
+    )
+}
+
+impl VecAndIndex<Result<PinyinToken, ParseError>> {
+    fn alph(&self, i: usize) -> Option<&AlphabetWithDiacritics> {
+        match self.vec.get(i) {
+            Some(Ok(PinyinToken::Alph(alph))) => Some(alph),
+            _ => None,
+        }
+    }
+
+    /// Finds the letter that should bear the tone denoted by a digit placed at `digit`.
+    /// Returns `None` if the letters before the digit already bear a tone or contain no vowel.
+    fn tone_bearer(&self, digit: usize) -> Option<usize> {
+        let toneless = |alph: &AlphabetWithDiacritics| !alph.diacritics.iter().any(is_tone);
+
+        // skip the coda, and the erhua
+        let mut end = digit;
+        while let Some(alph) = end.checked_sub(1).and_then(|i| self.alph(i)) {
+            if !matches!(
+                alph.alphabet,
+                Alphabet::N | Alphabet::G | Alphabet::Ŋ | Alphabet::R
+            ) || !toneless(alph)
+            {
+                break;
+            }
+            end -= 1;
+        }
+
+        let mut start = end;
+        while let Some(alph) = start.checked_sub(1).and_then(|i| self.alph(i)) {
+            if !is_vowel(alph.alphabet) {
+                break;
+            }
+            start -= 1;
+        }
+
+        if start == end {
+            // syllabic nasals: m2, n4
+            let i = digit.checked_sub(1)?;
+            let alph = self.alph(i)?;
+            let lone = i.checked_sub(1).and_then(|j| self.alph(j)).is_none();
+            return (lone && matches!(alph.alphabet, Alphabet::M | Alphabet::N) && toneless(alph))
+                .then_some(i);
+        }
+
+        if !(start..end).all(|i| self.alph(i).is_some_and(toneless)) {
+            return None;
+        }
+
+        let letters = (start..end)
+            .map(|i| self.alph(i).map(|alph| alph.alphabet))
+            .collect::<Vec<_>>();
+        let position = letters
+            .iter()
+            .position(|a| matches!(a, Some(Alphabet::A | Alphabet::E)))
+            .or_else(|| {
+                letters
+                    .windows(2)
+                    .position(|w| w == [Some(Alphabet::O), Some(Alphabet::U)])
+            })
+            .unwrap_or(letters.len() - 1);
+        Some(start + position)
+    }
+
+    /// Reads `u:` as `ü`, as CC-CEDICT writes `lu:4` for `lǜ`.
+    fn apply_u_colon(&mut self) {
+        let mut i = 1;
+        while i < self.vec.len() {
+            let colon = matches!(&self.vec[i], Ok(PinyinToken::Others(s)) if s == ":");
+            match self.vec.get_mut(i - 1) {
+                Some(Ok(PinyinToken::Alph(alph)))
+                    if colon && alph.alphabet == Alphabet::U && alph.diacritics.is_empty() =>
+                {
+                    alph.diacritics.push(Diacritic::Umlaut);
+                    self.remove(i);
+                }
+                _ => i += 1,
+            }
+        }
+    }
+
+    /// Replaces each tone number with the corresponding diacritic.
+    /// A digit followed by `a`, `e` or `o` becomes an apostrophe, since it marks the end of a syllable.
+    /// A digit right after a letter that cannot bear its tone becomes [`ParseError::MisplacedToneNumber`];
+    /// other digits are left as they are.
+    pub fn apply_tone_numbers(&mut self) {
+        self.apply_u_colon();
+        let mut i = 0;
+        while i < self.vec.len() {
+            let tone = match &self.vec[i] {
+                Ok(PinyinToken::Others(s)) => tone_of_number(s),
+                _ => None,
+            };
+            let Some(tone) = tone else {
+                i += 1;
+                continue;
+            };
+            let Some(bearer) = self.tone_bearer(i) else {
+                let before = i.checked_sub(1).and_then(|j| self.alph(j));
+                if tone == Tone::Fifth && before.is_some_and(|alph| alph.alphabet == Alphabet::R) {
+                    // the `r5` of `hua4r5`
+                    self.remove(i);
+                    continue;
+                }
+                if before.is_some() {
+                    let Ok(PinyinToken::Others(found)) = &self.vec[i] else {
+                        unreachable!()
+                    };
+                    self.vec[i] = Err(ParseError::MisplacedToneNumber {
+                        found: found.clone(),
+                        span: self.span(i..i + 1),
+                    });
+                }
+                i += 1;
+                continue;
+            };
+            if let (Some(d), Some(Ok(PinyinToken::Alph(alph)))) =
+                (diacritic_of_tone(tone), self.vec.get_mut(bearer))
+            {
+                alph.diacritics.push(d);
+            }
+
+            // keep `fang1an4` from being read as `fān gàn`, as an apostrophe would
+            let aeo_follows = matches!(
+                self.alph(i + 1),
+                Some(alph) if matches!(alph.alphabet, Alphabet::A | Alphabet::E | Alphabet::O)
+            );
+            if aeo_follows {
+                self.vec[i] = Ok(PinyinToken::Apostrophe);
+                i += 1;
+            } else {
+                self.remove(i);
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{finals, NonRhoticFinal, SpellingInitial, Syllable, Tone};
+
+/// Displays a [`Syllable`] in Tongyong Pinyin, such as `jhong`. Created by [`Syllable::to_tongyong`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Tongyong {
+    syllable: Syllable,
+    mark_tones: bool,
+}
+
+impl Tongyong {
+    /// Leaves out the tone marks, as on street signs, if `b` is `false`
+    #[must_use]
+    pub const fn mark_tones(self, b: bool) -> Self {
+        Self {
+            mark_tones: b,
+            ..self
+        }
+    }
+}
+
+pub const fn initial(initial: SpellingInitial) -> &'static str {
+    use SpellingInitial::{
+        ZeroAEO, B, C, CH, D, F, G, H, J, K, L, M, N, P, Q, R, S, SH, T, W, X, Y, Z, ZH,
+    };
+    match initial {
+        B => "b",
+        P => "p",
+        M => "m",
+        F => "f",
+        D => "d",
+        T => "t",
+        N => "n",
+        L => "l",
+        G => "g",
+        K => "k",
+        H => "h",
+        J => "j",
+        Q | C => "c",
+        X | S => "s",
+        ZH => "jh",
+        CH => "ch",
+        SH => "sh",
+        R => "r",
+        Z => "z",
+        Y | W | ZeroAEO => "",
+    }
+}
+
+/// The final as written without an initial, and after one
+pub const fn rhyme(fin: NonRhoticFinal) -> (&'static str, &'static str) {
+    use NonRhoticFinal as F;
+    match fin {
+        F::A => ("a", "a"),
+        F::O => ("o", "o"),
+        F::E => ("e", "e"),
+        F::Ê => ("ê", "ê"),
+        F::Ai => ("ai", "ai"),
+        F::Ei => ("ei", "ei"),
+        F::Ao => ("ao", "ao"),
+        F::Ou => ("ou", "ou"),
+        F::An => ("an", "an"),
+        F::En => ("en", "en"),
+        F::Ang => ("ang", "ang"),
+        F::Eng => ("eng", "eng"),
+        F::Ong => ("wong", "ong"),
+        F::I => ("yi", "i"),
+        F::Ia => ("ya", "ia"),
+        F::Io => ("yo", "io"),
+        F::Ie => ("ye", "ie"),
+        F::Iao => ("yao", "iao"),
+        F::Iu => ("you", "iou"),
+        F::Ian => ("yan", "ian"),
+        F::In => ("yin", "in"),
+        F::Iang => ("yang", "iang"),
+        F::Ing => ("ying", "ing"),
+        F::Iong => ("yong", "yong"),
+        F::U => ("wu", "u"),
+        F::Ua => ("wa", "ua"),
+        F::Uo => ("wo", "uo"),
+        F::Uai => ("wai", "uai"),
+        F::Ui => ("wei", "uei"),
+        F::Uan => ("wan", "uan"),
+        F::Un => ("wun", "un"),
+        F::Uang => ("wang", "uang"),
+        F::Ü => ("yu", "yu"),
+        F::Ue | F::Üe => ("yue", "yue"),
+        F::Üan => ("yuan", "yuan"),
+        F::Ün => ("yun", "yun"),
+        F::M => ("m", "m"),
+        F::N => ("n", "n"),
+    }
+}
+
+impl std::fmt::Display for Tongyong {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use NonRhoticFinal as F;
+        use SpellingInitial as I;
+        let Syllable {
+            initial: i,
+            fin,
+            tone,
+            erhua,
+            capitalized,
+            ..
+        } = self.syllable;
+        let underlying = finals::underlying(i, fin);
+        let spelling = match (i, underlying) {
+            (I::ZH | I::CH | I::SH | I::R | I::Z | I::C | I::S, F::I) => {
+                format!("{}ih", initial(i))
+            }
+            (I::ZeroAEO, F::E) if erhua => "er".to_owned(),
+            // `yai` has no final of its own
+            (I::Y, F::Ai) => "yai".to_owned(),
+            (I::Y | I::W | I::ZeroAEO, _) => rhyme(underlying).0.to_owned(),
+            (I::B | I::P | I::M | I::F, F::Eng) => format!("{}ong", initial(i)),
+            _ => format!("{}{}", initial(i), rhyme(underlying).1),
+        };
+        let erhua = if erhua && spelling != "er" { "r" } else { "" };
+
+        // the first tone is left unmarked, and the light tone takes a ring
+        let mark = match tone {
+            _ if !self.mark_tones => "",
+            Tone::First => "",
+            Tone::Second => "\u{301}",
+            Tone::Third => "\u{30c}",
+            Tone::Fourth => "\u{300}",
+            Tone::Fifth => "\u{30a}",
+        };
+        let spelling = finals::with_mark(&spelling, mark);
+        let mut chars = spelling.chars();
+        if capitalized {
+            if let Some(c) = chars.next() {
+                write!(f, "{}", c.to_uppercase())?;
+            }
+        }
+        write!(f, "{}{erhua}", chars.as_str())
+    }
+}
+
+impl Syllable {
+    /// Renders the syllable in Tongyong Pinyin, capitalized if the syllable was.
+    /// The first tone is left unmarked, and the light tone is marked with a ring.
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let tongyong = PinyinParser::new()
+    ///     .try_parse_syllables("Zhōngshān fēng cì sì wén xióng de")
+    ///     .map(|s| s.unwrap().to_tongyong().to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     tongyong,
+    ///     vec!["Jhong", "shan", "fong", "cìh", "sìh", "wún", "syóng", "de̊"]
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn to_tongyong(self) -> Tongyong {
+        Tongyong {
+            syllable: self,
+            mark_tones: true,
+        }
+    }
+}
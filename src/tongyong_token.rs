@@ -0,0 +1,242 @@
+use crate::inventory::FINALS;
+use crate::pinyin_token::{Alphabet, Diacritic, PinyinToken};
+use crate::tongyong::{initial, rhyme};
+use crate::{
+    finals, NonRhoticFinal, ParseError, SpellingInitial, Strictness, Syllable, Tone, VecAndIndex,
+};
+use unicode_normalization::UnicodeNormalization;
+
+/// The finals, to look the spellings up in; `ue` is written as `üe` is
+fn finals() -> impl Iterator<Item = NonRhoticFinal> {
+    FINALS.into_iter().filter(|f| *f != NonRhoticFinal::Ue)
+}
+
+/// Initials in Tongyong Pinyin, the longer ones first
+const INITIALS: [SpellingInitial; 19] = {
+    use SpellingInitial::{B, C, CH, D, F, G, H, J, K, L, M, N, P, R, S, SH, T, Z, ZH};
+    [ZH, CH, SH, B, P, M, F, D, T, N, L, G, K, H, J, C, S, R, Z]
+};
+
+/// Spellings that Tongyong Pinyin does not use, but that are often seen in its place
+fn nonstandard_final(initial: SpellingInitial, rest: &str) -> Option<NonRhoticFinal> {
+    use NonRhoticFinal as F;
+    use SpellingInitial as I;
+    Some(match (initial, rest) {
+        (I::ZeroAEO, "wen") => F::Un,
+        (I::ZeroAEO, "weng") => F::Ong,
+        (I::ZeroAEO, _) => return None,
+        (I::J | I::C | I::S, "u") => F::Ü,
+        (I::J | I::C | I::S, "ue") => F::Üe,
+        (I::J | I::C | I::S, "uan") => F::Üan,
+        (I::J | I::C | I::S, "un") => F::Ün,
+        (I::J | I::C | I::S, "iong") => F::Iong,
+        (_, "iu") => F::Iu,
+        (_, "ui") => F::Ui,
+        _ => return None,
+    })
+}
+
+/// Reads a toneless Tongyong syllable without the erhua, such as `jhong`, as pinyin.
+/// The spellings that are often seen in place of Tongyong Pinyin are accepted unless `strictness` is strict.
+fn read_plain(s: &str, strictness: Strictness) -> Option<Syllable> {
+    use NonRhoticFinal as F;
+    use SpellingInitial as I;
+    let syllable = |initial, fin| {
+        let (initial, fin) = finals::spelled(initial, fin);
+        Syllable {
+            initial,
+            fin,
+            tone: Tone::Fifth,
+            erhua: false,
+            capitalized: false,
+            uppercase: 0,
+        }
+    };
+
+    let loose = !strictness.is_strict();
+    if s == "yai" {
+        return Some(Syllable {
+            initial: I::Y,
+            ..syllable(None, F::Ai)
+        });
+    }
+    if let Some(fin) = finals().find(|f| rhyme(*f).0 == s) {
+        return Some(syllable(None, fin));
+    }
+    if let Some(fin) = nonstandard_final(I::ZeroAEO, s).filter(|_| loose) {
+        return Some(syllable(None, fin));
+    }
+    let (tongyong_initial, rest) = INITIALS
+        .into_iter()
+        .find_map(|i| Some((i, s.strip_prefix(initial(i))?)))?;
+
+    // the empty rhyme, also spelled as in `jhi` instead of `jhih`
+    match (tongyong_initial, rest) {
+        (I::ZH | I::CH | I::SH | I::R | I::Z | I::C | I::S, "ih")
+        | (I::ZH | I::CH | I::SH | I::R | I::Z, "i") => {
+            return (rest == "ih" || loose).then(|| syllable(Some(tongyong_initial), F::I));
+        }
+        _ => {}
+    }
+
+    let with_final = |fin| {
+        let fin = match (tongyong_initial, fin) {
+            (I::B | I::P | I::M | I::F, F::Ong) => F::Eng,
+            (I::B | I::P | I::M | I::F, F::Eng) if !loose => return None,
+            (_, fin) => fin,
+        };
+        let palatal = fin.is_palatal();
+        let pinyin_initial = match tongyong_initial {
+            I::J if palatal => I::J,
+            I::C if palatal => I::Q,
+            I::S if palatal => I::X,
+            I::N | I::L => tongyong_initial,
+            I::B | I::P | I::M | I::D | I::T
+                if !matches!(fin, F::Ü | F::Ue | F::Üe | F::Üan | F::Ün | F::Iong) =>
+            {
+                tongyong_initial
+            }
+            I::J => return None,
+            _ if palatal => return None,
+            i => i,
+        };
+        Some(syllable(Some(pinyin_initial), fin))
+    };
+    finals()
+        .find(|f| !matches!(f, F::M | F::N) && rhyme(*f).1 == rest)
+        .and_then(with_final)
+        .or_else(|| {
+            nonstandard_final(tongyong_initial, rest)
+                .filter(|_| loose)
+                .and_then(with_final)
+        })
+}
+
+/// Reads a toneless Tongyong syllable, such as `jhong` or `huar`, as pinyin
+fn read(s: &str, strictness: Strictness) -> Option<Syllable> {
+    if s == "er" {
+        return Some(Syllable {
+            initial: SpellingInitial::ZeroAEO,
+            fin: NonRhoticFinal::E,
+            tone: Tone::Fifth,
+            erhua: true,
+            capitalized: false,
+            uppercase: 0,
+        });
+    }
+    read_plain(s, strictness).or_else(|| {
+        let syllable = read_plain(s.strip_suffix('r')?, strictness)?;
+        Some(Syllable {
+            erhua: true,
+            ..syllable
+        })
+    })
+}
+
+impl VecAndIndex<Result<PinyinToken, ParseError>> {
+    /// The letter `vec[i]` stands for in Tongyong Pinyin, along with the tone it is marked with.
+    /// The ring of the light tone, as on `å` or `ê̊`, is not a diacritic of pinyin, so such letters are found among the others.
+    fn tongyong_letter(&self, i: usize) -> Option<(char, Option<Tone>)> {
+        match self.vec.get(i)? {
+            Ok(PinyinToken::Alph(alph)) => {
+                let letter = match (
+                    alph.alphabet,
+                    alph.diacritics.contains(&Diacritic::Circumflex),
+                ) {
+                    (Alphabet::E, true) => 'ê',
+                    (a, _) => a.to_low().chars().next()?,
+                };
+                let tone = alph.diacritics.iter().find_map(|d| match d {
+                    Diacritic::Macron => Some(Tone::First),
+                    Diacritic::Acute => Some(Tone::Second),
+                    Diacritic::Hacek | Diacritic::Breve => Some(Tone::Third),
+                    Diacritic::Grave => Some(Tone::Fourth),
+                    Diacritic::Umlaut | Diacritic::Circumflex => None,
+                });
+                Some((letter, tone))
+            }
+            Ok(PinyinToken::Others(s)) => {
+                let mut chars = s.nfd();
+                let letter = chars
+                    .next()
+                    .filter(char::is_ascii_alphabetic)?
+                    .to_ascii_lowercase();
+                let marks = chars.collect::<Vec<_>>();
+                let letter = match (letter, &marks[..]) {
+                    (_, ['\u{30a}']) => letter,
+                    ('e', ['\u{302}', '\u{30a}'] | ['\u{30a}', '\u{302}']) => 'ê',
+                    _ => return None,
+                };
+                Some((letter, Some(Tone::Fifth)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Replaces each run of letters that splits up into Tongyong syllables with the same syllables in pinyin.
+    /// A syllable without a tone mark is taken to be in the first tone, which Tongyong Pinyin leaves unmarked.
+    /// A run of letters that cannot be split into Tongyong syllables is an error.
+    pub fn apply_tongyong(&mut self, strictness: Strictness) {
+        let mut syllables = vec![];
+        let mut i = 0;
+        while i < self.vec.len() {
+            let mut letters = vec![];
+            while let Some(letter) = self.tongyong_letter(i + letters.len()) {
+                letters.push(letter);
+            }
+            if letters.is_empty() {
+                i += 1;
+                continue;
+            }
+            let chars = letters.iter().map(|(c, _)| *c).collect::<Vec<_>>();
+            // no syllable is longer than `jhuangr`
+            let segments = crate::segment(&chars, 7, |_, s| {
+                read(&s.iter().collect::<String>(), strictness)
+            });
+            let Some(segments) = segments else {
+                let error = ParseError::InvalidTongyong {
+                    found: chars.iter().collect(),
+                    span: self.span(i..i + letters.len()),
+                };
+                syllables.push((i, letters.len(), Err(error)));
+                i += letters.len();
+                continue;
+            };
+            let mut start = 0;
+            for (len, syllable) in segments {
+                let tone = letters[start..start + len]
+                    .iter()
+                    .find_map(|(_, tone)| *tone)
+                    .unwrap_or(Tone::First);
+                let capitalized = matches!(
+                    &self.vec[i + start],
+                    Ok(PinyinToken::Alph(alph)) if alph.capitalized
+                ) || matches!(
+                    &self.vec[i + start],
+                    Ok(PinyinToken::Others(s)) if s.starts_with(char::is_uppercase)
+                );
+                syllables.push((
+                    i + start,
+                    len,
+                    Ok(Syllable {
+                        tone,
+                        capitalized,
+                        uppercase: u16::from(capitalized),
+                        ..syllable
+                    }),
+                ));
+                start += len;
+            }
+            i += letters.len();
+        }
+
+        let mut syllables = syllables.into_iter().peekable();
+        self.replace_with(strictness, |_, i| {
+            let (_, len, syllable) = syllables.next_if(|(start, _, _)| *start == i)?;
+            Some((
+                len,
+                syllable.map(|s| s.to_string_preserving_capitalization()),
+            ))
+        });
+    }
+}
@@ -0,0 +1,373 @@
+use crate::{finals, NonRhoticFinal, SpellingInitial, Syllable, Tone};
+
+/// An initial as pronounced.
+///
+/// Unlike [`SpellingInitial`], the `y` and `w` of the spelling are not initials,
+/// and the syllables without an initial all begin with [`Initial::Zero`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Initial {
+    /// No initial, as in `a`, `yi`, `wen` or `yu`
+    Zero,
+    B,
+    P,
+    M,
+    F,
+    D,
+    T,
+    N,
+    L,
+    G,
+    K,
+    H,
+    J,
+    Q,
+    X,
+    ZH,
+    CH,
+    SH,
+    R,
+    Z,
+    C,
+    S,
+}
+
+impl Initial {
+    const fn from_spelling(initial: SpellingInitial) -> Self {
+        use SpellingInitial as I;
+        match initial {
+            I::ZeroAEO | I::Y | I::W => Self::Zero,
+            I::B => Self::B,
+            I::P => Self::P,
+            I::M => Self::M,
+            I::F => Self::F,
+            I::D => Self::D,
+            I::T => Self::T,
+            I::N => Self::N,
+            I::L => Self::L,
+            I::G => Self::G,
+            I::K => Self::K,
+            I::H => Self::H,
+            I::J => Self::J,
+            I::Q => Self::Q,
+            I::X => Self::X,
+            I::ZH => Self::ZH,
+            I::CH => Self::CH,
+            I::SH => Self::SH,
+            I::R => Self::R,
+            I::Z => Self::Z,
+            I::C => Self::C,
+            I::S => Self::S,
+        }
+    }
+
+    /// `None` stands for the lack of an initial, as in [`finals::spelled`].
+    const fn to_spelling(self) -> Option<SpellingInitial> {
+        use SpellingInitial as I;
+        Some(match self {
+            Self::Zero => return None,
+            Self::B => I::B,
+            Self::P => I::P,
+            Self::M => I::M,
+            Self::F => I::F,
+            Self::D => I::D,
+            Self::T => I::T,
+            Self::N => I::N,
+            Self::L => I::L,
+            Self::G => I::G,
+            Self::K => I::K,
+            Self::H => I::H,
+            Self::J => I::J,
+            Self::Q => I::Q,
+            Self::X => I::X,
+            Self::ZH => I::ZH,
+            Self::CH => I::CH,
+            Self::SH => I::SH,
+            Self::R => I::R,
+            Self::Z => I::Z,
+            Self::C => I::C,
+            Self::S => I::S,
+        })
+    }
+}
+
+impl std::fmt::Display for Initial {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let initial = self.to_spelling().unwrap_or(SpellingInitial::ZeroAEO);
+        write!(f, "{initial}")
+    }
+}
+
+/// A final in its full form, as listed in the table of finals of the Scheme for the Chinese Phonetic Alphabet.
+///
+/// `liu` has [`Final::Iou`], `gui` has [`Final::Uei`], and both `ju` and `yu` have [`Final::Ü`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Final {
+    A,
+    O,
+    E,
+    Ê,
+    Ai,
+    Ei,
+    Ao,
+    Ou,
+    An,
+    En,
+    Ang,
+    Eng,
+    Ong,
+    I,
+    Ia,
+    Io,
+    Ie,
+    /// as in `yai`
+    Iai,
+    Iao,
+    Iou,
+    Ian,
+    In,
+    Iang,
+    Ing,
+    Iong,
+    U,
+    Ua,
+    Uo,
+    Uai,
+    Uei,
+    Uan,
+    Uen,
+    Uang,
+    /// as in `weng`
+    Ueng,
+    Ü,
+    Üe,
+    Üan,
+    Ün,
+    /// syllabic `m`, as in `ḿ`
+    M,
+    /// syllabic `n`, as in `ń`
+    N,
+}
+
+impl Final {
+    const fn from_underlying(fin: NonRhoticFinal) -> Self {
+        use NonRhoticFinal as F;
+        match fin {
+            F::A => Self::A,
+            F::O => Self::O,
+            F::E => Self::E,
+            F::Ê => Self::Ê,
+            F::Ai => Self::Ai,
+            F::Ei => Self::Ei,
+            F::Ao => Self::Ao,
+            F::Ou => Self::Ou,
+            F::An => Self::An,
+            F::En => Self::En,
+            F::Ang => Self::Ang,
+            F::Eng => Self::Eng,
+            F::Ong => Self::Ong,
+            F::I => Self::I,
+            F::Ia => Self::Ia,
+            F::Io => Self::Io,
+            F::Ie => Self::Ie,
+            F::Iao => Self::Iao,
+            F::Iu => Self::Iou,
+            F::Ian => Self::Ian,
+            F::In => Self::In,
+            F::Iang => Self::Iang,
+            F::Ing => Self::Ing,
+            F::Iong => Self::Iong,
+            F::U => Self::U,
+            F::Ua => Self::Ua,
+            F::Uo => Self::Uo,
+            F::Uai => Self::Uai,
+            F::Ui => Self::Uei,
+            F::Uan => Self::Uan,
+            F::Un => Self::Uen,
+            F::Uang => Self::Uang,
+            F::Ü => Self::Ü,
+            F::Ue | F::Üe => Self::Üe,
+            F::Üan => Self::Üan,
+            F::Ün => Self::Ün,
+            F::M => Self::M,
+            F::N => Self::N,
+        }
+    }
+
+    /// The inverse of [`Final::from_underlying`]. `iai` and `ueng` never follow an initial,
+    /// and are then taken to be `ai` and `ong`.
+    const fn to_underlying(self) -> NonRhoticFinal {
+        use NonRhoticFinal as F;
+        match self {
+            Self::A => F::A,
+            Self::O => F::O,
+            Self::E => F::E,
+            Self::Ê => F::Ê,
+            Self::Ai | Self::Iai => F::Ai,
+            Self::Ei => F::Ei,
+            Self::Ao => F::Ao,
+            Self::Ou => F::Ou,
+            Self::An => F::An,
+            Self::En => F::En,
+            Self::Ang => F::Ang,
+            Self::Eng => F::Eng,
+            Self::Ong | Self::Ueng => F::Ong,
+            Self::I => F::I,
+            Self::Ia => F::Ia,
+            Self::Io => F::Io,
+            Self::Ie => F::Ie,
+            Self::Iao => F::Iao,
+            Self::Iou => F::Iu,
+            Self::Ian => F::Ian,
+            Self::In => F::In,
+            Self::Iang => F::Iang,
+            Self::Ing => F::Ing,
+            Self::Iong => F::Iong,
+            Self::U => F::U,
+            Self::Ua => F::Ua,
+            Self::Uo => F::Uo,
+            Self::Uai => F::Uai,
+            Self::Uei => F::Ui,
+            Self::Uan => F::Uan,
+            Self::Uen => F::Un,
+            Self::Uang => F::Uang,
+            Self::Ü => F::Ü,
+            Self::Üe => F::Üe,
+            Self::Üan => F::Üan,
+            Self::Ün => F::Ün,
+            Self::M => F::M,
+            Self::N => F::N,
+        }
+    }
+}
+
+impl std::fmt::Display for Final {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            Self::A => "a",
+            Self::O => "o",
+            Self::E => "e",
+            Self::Ê => "ê",
+            Self::Ai => "ai",
+            Self::Ei => "ei",
+            Self::Ao => "ao",
+            Self::Ou => "ou",
+            Self::An => "an",
+            Self::En => "en",
+            Self::Ang => "ang",
+            Self::Eng => "eng",
+            Self::Ong => "ong",
+            Self::I => "i",
+            Self::Ia => "ia",
+            Self::Io => "io",
+            Self::Ie => "ie",
+            Self::Iai => "iai",
+            Self::Iao => "iao",
+            Self::Iou => "iou",
+            Self::Ian => "ian",
+            Self::In => "in",
+            Self::Iang => "iang",
+            Self::Ing => "ing",
+            Self::Iong => "iong",
+            Self::U => "u",
+            Self::Ua => "ua",
+            Self::Uo => "uo",
+            Self::Uai => "uai",
+            Self::Uei => "uei",
+            Self::Uan => "uan",
+            Self::Uen => "uen",
+            Self::Uang => "uang",
+            Self::Ueng => "ueng",
+            Self::Ü => "ü",
+            Self::Üe => "üe",
+            Self::Üan => "üan",
+            Self::Ün => "ün",
+            Self::M => "m",
+            Self::N => "n",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// A syllable as pronounced, with an [`Initial`] and a [`Final`] in place of their spellings.
+/// Created by [`Syllable::to_underlying`]; its `Display` gives the full form, such as `liou` or `üe`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct UnderlyingSyllable {
+    pub initial: Initial,
+    pub fin: Final,
+    pub tone: Tone,
+
+    /// Whether the syllable is rhotacized, as in `diǎnr` or `ér`
+    pub erhua: bool,
+}
+
+impl UnderlyingSyllable {
+    /// Spells the syllable out in standard pinyin, in lowercase.
+    /// ```
+    /// use pinyin_parser::{Final, Initial, Tone, UnderlyingSyllable};
+    /// let syllable = UnderlyingSyllable {
+    ///     initial: Initial::Zero,
+    ///     fin: Final::Uen,
+    ///     tone: Tone::Fourth,
+    ///     erhua: false,
+    /// };
+    /// assert_eq!(syllable.to_syllable().to_string(), "wèn");
+    /// ```
+    #[must_use]
+    pub const fn to_syllable(self) -> Syllable {
+        let (initial, fin) = match (self.initial.to_spelling(), self.fin) {
+            (None, Final::Iai) => (SpellingInitial::Y, NonRhoticFinal::Ai),
+            (None, Final::Ueng) => (SpellingInitial::W, NonRhoticFinal::Eng),
+            (initial, fin) => finals::spelled(initial, fin.to_underlying()),
+        };
+        Syllable {
+            initial,
+            fin,
+            tone: self.tone,
+            erhua: self.erhua,
+            capitalized: false,
+            uppercase: 0,
+        }
+    }
+}
+
+impl std::fmt::Display for UnderlyingSyllable {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}{}", self.initial, self.fin)?;
+        if self.erhua {
+            write!(f, "r")?;
+        }
+        Ok(())
+    }
+}
+
+impl Syllable {
+    /// The initial and the final as pronounced, with the `y`, `w` and the `u` standing for `ü` taken into account.
+    /// [`UnderlyingSyllable::to_syllable`] spells it back out.
+    /// ```
+    /// use pinyin_parser::{Final, Initial, PinyinParser};
+    /// let underlying = PinyinParser::new()
+    ///     .try_parse_syllables("liù guì yú jù wén")
+    ///     .map(|s| s.unwrap().to_underlying())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!((underlying[0].initial, underlying[0].fin), (Initial::L, Final::Iou));
+    /// assert_eq!((underlying[1].initial, underlying[1].fin), (Initial::G, Final::Uei));
+    /// assert_eq!((underlying[2].initial, underlying[2].fin), (Initial::Zero, Final::Ü));
+    /// assert_eq!((underlying[3].initial, underlying[3].fin), (Initial::J, Final::Ü));
+    /// assert_eq!((underlying[4].initial, underlying[4].fin), (Initial::Zero, Final::Uen));
+    /// assert_eq!(underlying[0].to_syllable().to_string(), "liù");
+    /// ```
+    #[must_use]
+    pub const fn to_underlying(self) -> UnderlyingSyllable {
+        let fin = match (self.initial, self.fin) {
+            (SpellingInitial::Y, NonRhoticFinal::Ai) => Final::Iai,
+            (SpellingInitial::W, NonRhoticFinal::Eng) => Final::Ueng,
+            (initial, fin) => Final::from_underlying(finals::underlying(initial, fin)),
+        };
+        UnderlyingSyllable {
+            initial: Initial::from_spelling(self.initial),
+            fin,
+            tone: self.tone,
+            erhua: self.erhua,
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{
+    finals, NonRhoticFinal, ParseError, PinyinParser, Romanization, SpellingInitial, Syllable, Tone,
+};
+
+/// How the tone is written after a syllable
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum ToneNumberStyle {
+    /// `chung¹`
+    #[default]
+    Superscript,
+
+    /// `chung1`
+    Trailing,
+}
+
+/// Displays a [`Syllable`] in Wade–Giles, such as `chung¹`. Created by [`Syllable::to_wade_giles`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct WadeGiles {
+    syllable: Syllable,
+    style: ToneNumberStyle,
+}
+
+const fn initial(initial: SpellingInitial) -> &'static str {
+    use SpellingInitial::{
+        ZeroAEO, B, C, CH, D, F, G, H, J, K, L, M, N, P, Q, R, S, SH, T, W, X, Y, Z, ZH,
+    };
+    match initial {
+        B => "p",
+        P => "p'",
+        M => "m",
+        F => "f",
+        D => "t",
+        T => "t'",
+        N => "n",
+        L => "l",
+        G => "k",
+        K => "k'",
+        H => "h",
+        J | ZH => "ch",
+        Q | CH => "ch'",
+        X => "hs",
+        SH => "sh",
+        R => "j",
+        Z => "ts",
+        C => "ts'",
+        S => "s",
+        Y | W | ZeroAEO => "",
+    }
+}
+
+const fn rhyme(fin: NonRhoticFinal) -> &'static str {
+    use NonRhoticFinal as F;
+    match fin {
+        F::A => "a",
+        F::O => "o",
+        F::E => "ê",
+        F::Ê => "eh",
+        F::Ai => "ai",
+        F::Ei => "ei",
+        F::Ao => "ao",
+        F::Ou => "ou",
+        F::An => "an",
+        F::En => "ên",
+        F::Ang => "ang",
+        F::Eng => "êng",
+        F::I => "i",
+        F::Ia => "ia",
+        F::Io => "io",
+        F::Ie => "ieh",
+        F::Iao => "iao",
+        F::Iu => "iu",
+        F::Ian => "ien",
+        F::In => "in",
+        F::Iang => "iang",
+        F::Ing => "ing",
+        F::Iong => "iung",
+        F::U => "u",
+        F::Ua => "ua",
+        F::Uo => "uo",
+        F::Uai => "uai",
+        F::Ui => "ui",
+        F::Uan => "uan",
+        F::Un => "un",
+        F::Uang => "uang",
+        F::Ong => "ung",
+        F::Ü => "ü",
+        F::Ue | F::Üe => "üeh",
+        F::Üan => "üan",
+        F::Ün => "ün",
+        F::M => "m",
+        F::N => "n",
+    }
+}
+
+/// Syllables spelled with `y` or `w`, which Wade–Giles writes differently from the corresponding finals
+const fn y_w(fin: NonRhoticFinal) -> Option<&'static str> {
+    use NonRhoticFinal as F;
+    Some(match fin {
+        F::I => "i",
+        F::In => "yin",
+        F::Ing => "ying",
+        F::Ia => "ya",
+        F::Io => "yo",
+        F::Ie => "yeh",
+        F::Ai => "yai",
+        F::Iao => "yao",
+        F::Iu => "yu",
+        F::Ian => "yen",
+        F::Iang => "yang",
+        F::Iong => "yung",
+        F::Ü => "yü",
+        F::Ue | F::Üe => "yüeh",
+        F::Üan => "yüan",
+        F::Ün => "yün",
+        F::U => "wu",
+        F::Ua => "wa",
+        F::Uo => "wo",
+        F::Uai => "wai",
+        F::Ui => "wei",
+        F::Uan => "wan",
+        F::Un => "wên",
+        F::Uang => "wang",
+        F::Ong => "wêng",
+        _ => return None,
+    })
+}
+
+impl WadeGiles {
+    fn spelling(&self) -> String {
+        use NonRhoticFinal as F;
+        use SpellingInitial as I;
+        let Syllable {
+            initial: i,
+            fin,
+            erhua,
+            ..
+        } = self.syllable;
+        let underlying = finals::underlying(i, fin);
+        if (i, underlying, erhua) == (I::ZeroAEO, F::E, true) {
+            return "êrh".to_owned();
+        }
+        let erhua = if erhua { "rh" } else { "" };
+        if let (I::Y | I::W, Some(s)) = (i, y_w(underlying)) {
+            return format!("{s}{erhua}");
+        }
+        match (i, underlying) {
+            (I::ZH | I::CH | I::SH | I::R, F::I) => format!("{}ih{erhua}", initial(i)),
+            (I::Z, F::I) => format!("tzŭ{erhua}"),
+            (I::C, F::I) => format!("tz'ŭ{erhua}"),
+            (I::S, F::I) => format!("ssŭ{erhua}"),
+            (I::G | I::K | I::H, F::E)
+            | (I::D | I::T | I::N | I::L | I::Z | I::C | I::S | I::R | I::ZH | I::CH, F::Uo) => {
+                format!("{}o{erhua}", initial(i))
+            }
+            (I::G | I::K, F::Ui) => format!("{}uei{erhua}", initial(i)),
+            _ => format!("{}{}{erhua}", initial(i), rhyme(underlying)),
+        }
+    }
+}
+
+impl std::fmt::Display for WadeGiles {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let spelling = self.spelling();
+        let mut chars = spelling.chars();
+        if self.syllable.capitalized {
+            if let Some(c) = chars.next() {
+                write!(f, "{}", c.to_uppercase())?;
+            }
+        }
+        let tone = match (self.syllable.tone, self.style) {
+            (Tone::First, ToneNumberStyle::Superscript) => "¹",
+            (Tone::Second, ToneNumberStyle::Superscript) => "²",
+            (Tone::Third, ToneNumberStyle::Superscript) => "³",
+            (Tone::Fourth, ToneNumberStyle::Superscript) => "⁴",
+            (Tone::First, ToneNumberStyle::Trailing) => "1",
+            (Tone::Second, ToneNumberStyle::Trailing) => "2",
+            (Tone::Third, ToneNumberStyle::Trailing) => "3",
+            (Tone::Fourth, ToneNumberStyle::Trailing) => "4",
+            (Tone::Fifth, _) => "",
+        };
+        write!(f, "{}{tone}", chars.as_str())
+    }
+}
+
+impl Syllable {
+    /// Renders the syllable in Wade–Giles. The light tone is left unnumbered, and the first letter is
+    /// capitalized if the syllable was.
+    /// ```
+    /// use pinyin_parser::{PinyinParser, ToneNumberStyle};
+    /// let wade_giles = PinyinParser::new()
+    ///     .try_parse_syllables("Qīngdǎo cíqì")
+    ///     .map(|s| s.unwrap().to_wade_giles(ToneNumberStyle::Trailing).to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(wade_giles, vec!["Ch'ing1", "tao3", "tz'ŭ2", "ch'i4"]);
+    /// ```
+    #[must_use]
+    pub const fn to_wade_giles(self, style: ToneNumberStyle) -> WadeGiles {
+        WadeGiles {
+            syllable: self,
+            style,
+        }
+    }
+}
+
+/// Wade–Giles with the syllables of a word joined by hyphens
+struct WadeGilesWords(ToneNumberStyle);
+
+impl Romanization for WadeGilesWords {
+    fn syllable(&self, syllable: Syllable) -> String {
+        syllable.to_wade_giles(self.0).to_string()
+    }
+
+    fn separator(&self, _previous: &str, _next: &str) -> String {
+        "-".to_owned()
+    }
+}
+
+impl PinyinParser {
+    /// Converts `s` into Wade–Giles, joining the syllables of a word with hyphens.
+    /// Spaces, punctuations and other characters are kept as they are.
+    /// # Errors
+    /// Returns the first error that [`PinyinParser::try_parse`] finds.
+    /// ```
+    /// use pinyin_parser::{PinyinParser, Strictness, ToneNumberStyle};
+    /// assert_eq!(
+    ///     PinyinParser::new()
+    ///         .with_strictness(Strictness::Strict)
+    ///         .try_to_wade_giles("Zhōngguó", ToneNumberStyle::Superscript)
+    ///         .unwrap(),
+    ///     "Chung¹-kuo²"
+    /// );
+    /// ```
+    pub fn try_to_wade_giles(self, s: &str, style: ToneNumberStyle) -> Result<String, ParseError> {
+        self.try_romanize(s, &WadeGilesWords(style))
+    }
+}
@@ -0,0 +1,354 @@
+use crate::pinyin_token::{Alphabet, Diacritic, PinyinToken};
+use crate::{
+    finals, NonRhoticFinal, ParseError, PinyinParser, Span, SpellingInitial, Strictness, Syllable,
+    Tone, VecAndIndex,
+};
+use std::ops::Range;
+
+/// A Wade–Giles syllable that can be read as more than one pinyin syllable,
+/// typically because the `ü` lost its diaeresis. Found by [`PinyinParser::wade_giles_ambiguities`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WadeGilesAmbiguity {
+    pub span: Span,
+
+    /// The possible readings in pinyin, without the tone. The first one is what the parser picks.
+    pub readings: Vec<String>,
+}
+
+/// A Wade–Giles syllable found among the tokens
+struct WadeGilesSyllable {
+    tokens: Range<usize>,
+    capitalized: bool,
+
+    /// Empty if the letters cannot be split into syllables
+    readings: Vec<Syllable>,
+}
+
+/// Initials in Wade–Giles, the longer ones first
+const INITIALS: [&str; 25] = [
+    "ch'", "ts'", "tz'", "k'", "p'", "t'", "ch", "hs", "ts", "tz", "ss", "sz", "sh", "p", "m", "f",
+    "t", "n", "l", "k", "h", "j", "s", "y", "w",
+];
+
+/// Finals that do not depend on the initial
+fn final_of(s: &str) -> Option<NonRhoticFinal> {
+    use NonRhoticFinal as F;
+    Some(match s {
+        "a" => F::A,
+        "o" => F::O,
+        "ê" | "e" => F::E,
+        "eh" => F::Ê,
+        "ai" => F::Ai,
+        "ei" => F::Ei,
+        "ao" => F::Ao,
+        "ou" => F::Ou,
+        "an" => F::An,
+        "ên" | "en" => F::En,
+        "ang" => F::Ang,
+        "êng" | "eng" => F::Eng,
+        "i" => F::I,
+        "ia" => F::Ia,
+        "io" => F::Io,
+        "ieh" => F::Ie,
+        "iao" => F::Iao,
+        "iu" => F::Iu,
+        "ien" => F::Ian,
+        "in" => F::In,
+        "iang" => F::Iang,
+        "ing" => F::Ing,
+        "iung" => F::Iong,
+        "u" => F::U,
+        "ua" => F::Ua,
+        "uo" => F::Uo,
+        "uai" => F::Uai,
+        "ui" | "uei" => F::Ui,
+        "uan" => F::Uan,
+        "un" => F::Un,
+        "uang" => F::Uang,
+        "ung" => F::Ong,
+        "ü" => F::Ü,
+        "üeh" | "ueh" => F::Üe,
+        "üan" => F::Üan,
+        "ün" => F::Ün,
+        _ => return None,
+    })
+}
+
+/// The possible finals, as pronounced, that `rest` stands for after `initial`
+fn finals_of(initial: &str, rest: &str) -> Vec<NonRhoticFinal> {
+    use NonRhoticFinal as F;
+    match (initial, rest) {
+        ("ch" | "ch'" | "sh" | "j", "ih")
+        | ("tz" | "tz'" | "ss" | "sz", "ŭ" | "u")
+        | ("ts" | "ts'" | "s", "ŭ") => vec![F::I],
+        ("y", "u") => vec![F::Iu, F::Ü],
+        ("y", "eh") => vec![F::Ie],
+        ("y", "en") => vec![F::Ian],
+        ("y", "ung") => vec![F::Iong],
+        ("y", "un" | "ün") | ("hs", "un") => vec![F::Ün],
+        ("y", "uan" | "üan") | ("hs", "uan") => vec![F::Üan],
+        ("y", "o") => vec![F::Io],
+        ("y", "in" | "ing") => final_of(rest).into_iter().collect(),
+        ("y", _) => final_of(&format!("i{rest}"))
+            .or_else(|| final_of(rest).filter(|f| matches!(f, F::Ü | F::Üe)))
+            .into_iter()
+            .collect(),
+        ("w", "u") => vec![F::U],
+        ("w", "ei") => vec![F::Ui],
+        ("w", "ên" | "en") => vec![F::Un],
+        ("w", "êng" | "eng") => vec![F::Ong],
+        ("w", _) => final_of(&format!("u{rest}")).into_iter().collect(),
+        ("k" | "k'" | "h", "o") => vec![F::E],
+        ("p" | "p'" | "m" | "f", "o") => vec![F::O],
+        ("", "o") => vec![F::E, F::O],
+        ("l", "o") => vec![F::Uo, F::O],
+        (_, "o") => vec![F::Uo],
+        ("hs", "u") => vec![F::Ü],
+        ("ch" | "ch'" | "l" | "n", "u") => vec![F::U, F::Ü],
+        ("ch" | "ch'", "un") => vec![F::Un, F::Ün],
+        ("ch" | "ch'", "uan") => vec![F::Uan, F::Üan],
+        _ => final_of(rest).into_iter().collect(),
+    }
+}
+
+/// The pinyin initial that `initial` stands for, before a final beginning with `i` or `ü` if `palatal`
+fn initial_of(initial: &str, palatal: bool) -> Option<SpellingInitial> {
+    use SpellingInitial as I;
+    Some(match initial {
+        "" | "y" | "w" => I::ZeroAEO,
+        "ch" if palatal => I::J,
+        "ch'" if palatal => I::Q,
+        "hs" if palatal => I::X,
+        "ch" => I::ZH,
+        "ch'" => I::CH,
+        "sh" => I::SH,
+        "j" => I::R,
+        "ts" | "tz" => I::Z,
+        "ts'" | "tz'" => I::C,
+        "s" | "ss" | "sz" => I::S,
+        "p" => I::B,
+        "p'" => I::P,
+        "m" => I::M,
+        "f" => I::F,
+        "t" => I::D,
+        "t'" => I::T,
+        "n" => I::N,
+        "l" => I::L,
+        "k" => I::G,
+        "k'" => I::K,
+        "h" => I::H,
+        _ => return None,
+    })
+}
+
+/// The possible readings of a Wade–Giles syllable, such as `ch'ing`, in pinyin
+fn readings(s: &str) -> Vec<Syllable> {
+    if matches!(s, "êrh" | "erh") {
+        return vec![Syllable {
+            initial: SpellingInitial::ZeroAEO,
+            fin: NonRhoticFinal::E,
+            tone: Tone::Fifth,
+            erhua: true,
+            capitalized: false,
+            uppercase: 0,
+        }];
+    }
+    // the syllabic nasals, as in `m²`
+    let nasal = match s {
+        "m" => Some(NonRhoticFinal::M),
+        "n" => Some(NonRhoticFinal::N),
+        _ => None,
+    };
+    if let Some(fin) = nasal {
+        return vec![Syllable {
+            initial: SpellingInitial::ZeroAEO,
+            fin,
+            tone: Tone::Fifth,
+            erhua: false,
+            capitalized: false,
+            uppercase: 0,
+        }];
+    }
+    let (s, erhua) = s
+        .strip_suffix("rh")
+        .filter(|s| !s.is_empty())
+        .map_or((s, false), |s| (s, true));
+    let initial = INITIALS
+        .iter()
+        .find(|i| s.starts_with(**i))
+        .copied()
+        .unwrap_or("");
+    let rest = &s[initial.len()..];
+
+    // `yai` has no final of its own
+    if (initial, rest) == ("y", "ai") {
+        return vec![Syllable {
+            initial: SpellingInitial::Y,
+            fin: NonRhoticFinal::Ai,
+            tone: Tone::Fifth,
+            erhua,
+            capitalized: false,
+            uppercase: 0,
+        }];
+    }
+    finals_of(initial, rest)
+        .into_iter()
+        .filter_map(|fin| {
+            use SpellingInitial as I;
+            // `-ih` and `-ŭ` stand for the empty rhyme, which is not palatal
+            let palatal = fin.is_palatal() && !(fin == NonRhoticFinal::I && rest != "i");
+            let pinyin_initial = initial_of(initial, palatal)?;
+            let fits = if palatal {
+                !matches!(
+                    pinyin_initial,
+                    I::ZH | I::CH | I::SH | I::R | I::Z | I::C | I::S | I::G | I::K | I::H | I::F
+                )
+            } else {
+                !matches!(pinyin_initial, I::J | I::Q | I::X)
+            };
+            if !fits {
+                return None;
+            }
+            let pinyin_initial = (pinyin_initial != I::ZeroAEO).then_some(pinyin_initial);
+            let (initial, fin) = finals::spelled(pinyin_initial, fin);
+            Some(Syllable {
+                initial,
+                fin,
+                tone: Tone::Fifth,
+                erhua,
+                capitalized: false,
+                uppercase: 0,
+            })
+        })
+        .collect()
+}
+
+/// Superscript digits, sometimes used for the tones
+fn digit_of_superscript(s: &str) -> Option<&'static str> {
+    Some(match s {
+        "¹" => "1",
+        "²" => "2",
+        "³" => "3",
+        "⁴" => "4",
+        "⁵" => "5",
+        "⁰" => "0",
+        _ => return None,
+    })
+}
+
+impl VecAndIndex<Result<PinyinToken, ParseError>> {
+    /// The letter `vec[i]` stands for in Wade–Giles; the apostrophe counts as a letter
+    fn wade_giles_letter(&self, i: usize) -> Option<char> {
+        match self.vec.get(i)? {
+            Ok(PinyinToken::Apostrophe) => Some('\''),
+            // the curly quote, when kept apart from the apostrophe, still marks the aspiration after an initial
+            Ok(PinyinToken::Others(s)) if s == "’" => {
+                let before = self.wade_giles_letter(i.checked_sub(1)?)?;
+                matches!(before, 'h' | 's' | 'z' | 'k' | 'p' | 't').then_some('\'')
+            }
+            Ok(PinyinToken::Alph(alph)) => Some(match (alph.alphabet, &alph.diacritics[..]) {
+                (Alphabet::U, [Diacritic::Umlaut, ..]) => 'ü',
+                (Alphabet::U, [Diacritic::Breve, ..]) => 'ŭ',
+                (Alphabet::E, [Diacritic::Circumflex, ..]) => 'ê',
+                (a, _) => a.to_low().chars().next()?,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Finds the Wade–Giles syllables in each run of letters
+    fn wade_giles_syllables(&self) -> Vec<WadeGilesSyllable> {
+        let mut ans = vec![];
+        let mut i = 0;
+        while i < self.vec.len() {
+            if !matches!(self.vec[i], Ok(PinyinToken::Alph(_))) {
+                i += 1;
+                continue;
+            }
+            let mut letters = vec![];
+            while let Some(c) = self.wade_giles_letter(i + letters.len()) {
+                letters.push(c);
+            }
+            let end = i + letters.len();
+            // no syllable is longer than `ch'uangrh`
+            let segments = crate::segment(&letters, 9, |start, s| {
+                // a syllable without an initial begins a word, or `hsian` would be read as `hsi` and `an`,
+                // or as `hsia` and a syllabic `n`
+                if start > 0 && (matches!(s[0], 'a' | 'e' | 'ê' | 'o') || matches!(s, ['m' | 'n']))
+                {
+                    return None;
+                }
+                let readings = readings(&s.iter().collect::<String>());
+                (!readings.is_empty()).then_some(readings)
+            })
+            .unwrap_or_else(|| vec![(letters.len(), vec![])]);
+            for (len, readings) in segments {
+                let capitalized =
+                    matches!(&self.vec[i], Ok(PinyinToken::Alph(alph)) if alph.capitalized);
+                ans.push(WadeGilesSyllable {
+                    tokens: i..i + len,
+                    capitalized,
+                    readings,
+                });
+                i += len;
+            }
+            i = end;
+        }
+        ans
+    }
+
+    /// Replaces each Wade–Giles syllable with the same syllable spelled in numberless, toneless pinyin,
+    /// and each superscript digit with the plain digit, so that the tone numbers can then be applied.
+    /// A run of letters that cannot be split into Wade–Giles syllables is an error.
+    pub fn apply_wade_giles(&mut self, strictness: Strictness) {
+        let syllables = self.wade_giles_syllables();
+        let mut syllables = syllables.into_iter().peekable();
+        self.replace_with(strictness, |this, i| {
+            if let Some(Ok(PinyinToken::Others(s))) = this.vec.get(i) {
+                return digit_of_superscript(s).map(|d| (1, Ok(d.to_owned())));
+            }
+            let syllable = syllables.next_if(|s| s.tokens.start == i)?;
+            let Some(reading) = syllable.readings.first() else {
+                let error = ParseError::InvalidWadeGiles {
+                    found: syllable
+                        .tokens
+                        .clone()
+                        .filter_map(|j| this.wade_giles_letter(j))
+                        .collect(),
+                    span: this.span(syllable.tokens.clone()),
+                };
+                return Some((syllable.tokens.len(), Err(error)));
+            };
+            let pinyin = Syllable {
+                capitalized: syllable.capitalized,
+                uppercase: u16::from(syllable.capitalized),
+                ..*reading
+            }
+            .to_string_preserving_capitalization();
+            Some((syllable.tokens.len(), Ok(pinyin)))
+        });
+    }
+}
+
+impl PinyinParser {
+    /// Lists the syllables in `s`, read as Wade–Giles, that can stand for more than one pinyin syllable.
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let ambiguities = PinyinParser::new().wade_giles_ambiguities("Chu Teh, Lu Hsün");
+    /// assert_eq!(ambiguities.len(), 2);
+    /// assert_eq!(ambiguities[0].readings, vec!["zhu", "ju"]);
+    /// assert_eq!(ambiguities[1].readings, vec!["lu", "lü"]);
+    /// ```
+    #[must_use]
+    pub fn wade_giles_ambiguities(self, s: &str) -> Vec<WadeGilesAmbiguity> {
+        let it = self.tokenize(s);
+        it.wade_giles_syllables()
+            .into_iter()
+            .filter(|syllable| syllable.readings.len() > 1)
+            .map(|syllable| WadeGilesAmbiguity {
+                span: it.span(syllable.tokens),
+                readings: syllable.readings.iter().map(ToString::to_string).collect(),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{finals, NonRhoticFinal, SpellingInitial, Syllable};
+
+/// Displays a [`Syllable`] in Yale romanization, such as `jūng`. Created by [`Syllable::to_yale`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Yale {
+    syllable: Syllable,
+}
+
+const fn initial(initial: SpellingInitial) -> &'static str {
+    use SpellingInitial::{
+        ZeroAEO, B, C, CH, D, F, G, H, J, K, L, M, N, P, Q, R, S, SH, T, W, X, Y, Z, ZH,
+    };
+    match initial {
+        B => "b",
+        P => "p",
+        M => "m",
+        F => "f",
+        D => "d",
+        T => "t",
+        N => "n",
+        L => "l",
+        G => "g",
+        K => "k",
+        H => "h",
+        J | ZH => "j",
+        Q | CH => "ch",
+        X => "sy",
+        SH => "sh",
+        R => "r",
+        Z => "dz",
+        C => "ts",
+        S => "s",
+        Y | W | ZeroAEO => "",
+    }
+}
+
+/// The final as written without an initial, and after one
+const fn rhyme(fin: NonRhoticFinal) -> (&'static str, &'static str) {
+    use NonRhoticFinal as F;
+    match fin {
+        F::A => ("a", "a"),
+        F::O => ("o", "wo"),
+        F::E | F::Ê => ("e", "e"),
+        F::Ai => ("ai", "ai"),
+        F::Ei => ("ei", "ei"),
+        F::Ao => ("au", "au"),
+        F::Ou => ("ou", "ou"),
+        F::An => ("an", "an"),
+        F::En => ("en", "en"),
+        F::Ang => ("ang", "ang"),
+        F::Eng => ("eng", "eng"),
+        F::Ong => ("weng", "ung"),
+        F::I => ("yi", "i"),
+        F::Ia => ("ya", "ya"),
+        F::Io => ("yo", "yo"),
+        F::Ie => ("ye", "ye"),
+        F::Iao => ("yau", "yau"),
+        F::Iu => ("you", "you"),
+        F::Ian => ("yan", "yan"),
+        F::In => ("yin", "in"),
+        F::Iang => ("yang", "yang"),
+        F::Ing => ("ying", "ing"),
+        F::Iong => ("yung", "yung"),
+        F::U => ("wu", "u"),
+        F::Ua => ("wa", "wa"),
+        F::Uo => ("wo", "wo"),
+        F::Uai => ("wai", "wai"),
+        F::Ui => ("wei", "wei"),
+        F::Uan => ("wan", "wan"),
+        F::Un => ("wen", "wun"),
+        F::Uang => ("wang", "wang"),
+        F::Ü => ("yu", "yu"),
+        F::Ue | F::Üe => ("ywe", "ywe"),
+        F::Üan => ("ywan", "ywan"),
+        F::Ün => ("yun", "yun"),
+        F::M => ("m", "m"),
+        F::N => ("n", "n"),
+    }
+}
+
+impl std::fmt::Display for Yale {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use SpellingInitial as I;
+        let Syllable {
+            initial: i,
+            fin,
+            tone,
+            erhua,
+            ..
+        } = self.syllable;
+        let underlying = finals::underlying(i, fin);
+        let spelling = match (i, underlying) {
+            (I::ZH | I::CH | I::SH, NonRhoticFinal::I) => format!("{}r", initial(i)),
+            (I::R, NonRhoticFinal::I) => "r".to_owned(),
+            (I::Z, NonRhoticFinal::I) => "dz".to_owned(),
+            (I::C | I::S, NonRhoticFinal::I) => format!("{}z", initial(i)),
+            (I::ZeroAEO, NonRhoticFinal::E) if erhua => "er".to_owned(),
+            (I::Y | I::W | I::ZeroAEO, _) => rhyme(underlying).0.to_owned(),
+            // `sy` already carries the `y` of the medial
+            (I::X, _) => format!("s{}", rhyme(underlying).0),
+            _ => format!("{}{}", initial(i), rhyme(underlying).1),
+        };
+        let erhua = if erhua && spelling != "er" { "r" } else { "" };
+        write!(f, "{}{erhua}", finals::with_tone_mark(&spelling, tone))
+    }
+}
+
+impl Syllable {
+    /// Renders the syllable in Yale romanization. The erhua is marked by a trailing `r`.
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let yale = PinyinParser::new()
+    ///     .try_parse_syllables("Zhōngguó xuéxí shì zì")
+    ///     .map(|s| s.unwrap().to_yale().to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(yale, vec!["jūng", "gwó", "sywé", "syí", "shr̀", "dz̀"]);
+    /// ```
+    #[must_use]
+    pub const fn to_yale(self) -> Yale {
+        Yale { syllable: self }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{finals, NonRhoticFinal, SpellingInitial, Syllable, Tone};
+
+/// Displays a [`Syllable`] in Zhuyin (Bopomofo), such as `ㄓㄨㄥ`. Created by [`Syllable::to_zhuyin`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Zhuyin {
+    syllable: Syllable,
+    mark_first_tone: bool,
+}
+
+impl Zhuyin {
+    /// Writes the first tone as `ˉ` instead of leaving it unmarked
+    #[must_use]
+    pub const fn mark_first_tone(self, b: bool) -> Self {
+        Self {
+            mark_first_tone: b,
+            ..self
+        }
+    }
+}
+
+const fn initial(initial: SpellingInitial) -> &'static str {
+    use SpellingInitial::{
+        ZeroAEO, B, C, CH, D, F, G, H, J, K, L, M, N, P, Q, R, S, SH, T, W, X, Y, Z, ZH,
+    };
+    match initial {
+        B => "ㄅ",
+        P => "ㄆ",
+        M => "ㄇ",
+        F => "ㄈ",
+        D => "ㄉ",
+        T => "ㄊ",
+        N => "ㄋ",
+        L => "ㄌ",
+        G => "ㄍ",
+        K => "ㄎ",
+        H => "ㄏ",
+        J => "ㄐ",
+        Q => "ㄑ",
+        X => "ㄒ",
+        ZH => "ㄓ",
+        CH => "ㄔ",
+        SH => "ㄕ",
+        R => "ㄖ",
+        Z => "ㄗ",
+        C => "ㄘ",
+        S => "ㄙ",
+        Y | W | ZeroAEO => "",
+    }
+}
+
+const fn rhyme(fin: NonRhoticFinal) -> &'static str {
+    use NonRhoticFinal as F;
+    match fin {
+        F::A => "ㄚ",
+        F::O => "ㄛ",
+        F::E => "ㄜ",
+        F::Ê => "ㄝ",
+        F::Ai => "ㄞ",
+        F::Ei => "ㄟ",
+        F::Ao => "ㄠ",
+        F::Ou => "ㄡ",
+        F::An => "ㄢ",
+        F::En => "ㄣ",
+        F::Ang => "ㄤ",
+        F::Eng => "ㄥ",
+        F::I => "ㄧ",
+        F::Ia => "ㄧㄚ",
+        F::Io => "ㄧㄛ",
+        F::Ie => "ㄧㄝ",
+        F::Iao => "ㄧㄠ",
+        F::Iu => "ㄧㄡ",
+        F::Ian => "ㄧㄢ",
+        F::In => "ㄧㄣ",
+        F::Iang => "ㄧㄤ",
+        F::Ing => "ㄧㄥ",
+        F::Iong => "ㄩㄥ",
+        F::U => "ㄨ",
+        F::Ua => "ㄨㄚ",
+        F::Uo => "ㄨㄛ",
+        F::Uai => "ㄨㄞ",
+        F::Ui => "ㄨㄟ",
+        F::Uan => "ㄨㄢ",
+        F::Un => "ㄨㄣ",
+        F::Uang => "ㄨㄤ",
+        F::Ong => "ㄨㄥ",
+        F::Ü => "ㄩ",
+        F::Ue | F::Üe => "ㄩㄝ",
+        F::Üan => "ㄩㄢ",
+        F::Ün => "ㄩㄣ",
+        F::M => "ㄇ",
+        F::N => "ㄋ",
+    }
+}
+
+impl std::fmt::Display for Zhuyin {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let Syllable {
+            initial: i,
+            fin,
+            tone,
+            erhua,
+            ..
+        } = self.syllable;
+
+        // ér is written with ㄦ alone, rather than with ㄜ followed by ㄦ
+        let (body, erhua) = if i == SpellingInitial::ZeroAEO && fin == NonRhoticFinal::E && erhua {
+            ("ㄦ".to_owned(), false)
+        } else {
+            let rhyme = match (i, fin) {
+                // the empty rhyme is not written
+                (
+                    SpellingInitial::ZH
+                    | SpellingInitial::CH
+                    | SpellingInitial::SH
+                    | SpellingInitial::R
+                    | SpellingInitial::Z
+                    | SpellingInitial::C
+                    | SpellingInitial::S,
+                    NonRhoticFinal::I,
+                ) => "",
+                (SpellingInitial::Y, NonRhoticFinal::Ai) => "ㄧㄞ",
+                _ => rhyme(finals::underlying(i, fin)),
+            };
+            (format!("{}{rhyme}", initial(i)), erhua)
+        };
+        let erhua = if erhua { "ㄦ" } else { "" };
+        match tone {
+            Tone::First if self.mark_first_tone => write!(f, "{body}ˉ{erhua}"),
+            Tone::First => write!(f, "{body}{erhua}"),
+            Tone::Second => write!(f, "{body}ˊ{erhua}"),
+            Tone::Third => write!(f, "{body}ˇ{erhua}"),
+            Tone::Fourth => write!(f, "{body}ˋ{erhua}"),
+            Tone::Fifth => write!(f, "˙{body}{erhua}"),
+        }
+    }
+}
+
+impl Syllable {
+    /// Renders the syllable in Zhuyin. The first tone is left unmarked unless [`Zhuyin::mark_first_tone`] is set.
+    /// ```
+    /// use pinyin_parser::PinyinParser;
+    /// let zhuyin = PinyinParser::new()
+    ///     .try_parse_syllables("Zhōngwén yīdiǎnr de")
+    ///     .map(|s| s.unwrap().to_zhuyin().to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(zhuyin, vec!["ㄓㄨㄥ", "ㄨㄣˊ", "ㄧ", "ㄉㄧㄢˇㄦ", "˙ㄉㄜ"]);
+    /// ```
+    #[must_use]
+    pub const fn to_zhuyin(self) -> Zhuyin {
+        Zhuyin {
+            syllable: self,
+            mark_first_tone: false,
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use crate::pinyin_token::PinyinToken;
+use crate::{
+    finals, NonRhoticFinal, ParseError, SpellingInitial, Strictness, Syllable, Tone, VecAndIndex,
+};
+
+/// A syllable written in Zhuyin, such as `ㄓㄨㄥ`
+struct ZhuyinSyllable {
+    initial: Option<SpellingInitial>,
+    medial: Option<char>,
+    rhyme: Option<char>,
+    tone: Tone,
+    erhua: bool,
+}
+
+const fn initial(c: char) -> Option<SpellingInitial> {
+    use SpellingInitial::{B, C, CH, D, F, G, H, J, K, L, M, N, P, Q, R, S, SH, T, X, Z, ZH};
+    Some(match c {
+        'ㄅ' => B,
+        'ㄆ' => P,
+        'ㄇ' => M,
+        'ㄈ' => F,
+        'ㄉ' => D,
+        'ㄊ' => T,
+        'ㄋ' => N,
+        'ㄌ' => L,
+        'ㄍ' => G,
+        'ㄎ' => K,
+        'ㄏ' => H,
+        'ㄐ' => J,
+        'ㄑ' => Q,
+        'ㄒ' => X,
+        'ㄓ' => ZH,
+        'ㄔ' => CH,
+        'ㄕ' => SH,
+        'ㄖ' => R,
+        'ㄗ' => Z,
+        'ㄘ' => C,
+        'ㄙ' => S,
+        _ => return None,
+    })
+}
+
+const fn tone_mark(c: char) -> Option<Tone> {
+    match c {
+        'ˉ' => Some(Tone::First),
+        'ˊ' => Some(Tone::Second),
+        'ˇ' => Some(Tone::Third),
+        'ˋ' => Some(Tone::Fourth),
+        _ => None,
+    }
+}
+
+impl ZhuyinSyllable {
+    /// Reads a syllable from the beginning of `chars`, returning it along with the number of characters consumed
+    fn read(chars: &[Option<char>]) -> Option<(Self, usize)> {
+        let mut i = 0;
+        let neutral = chars.first() == Some(&Some('˙'));
+        if neutral {
+            i += 1;
+        }
+        let initial = chars.get(i).copied().flatten().and_then(initial);
+        if initial.is_some() {
+            i += 1;
+        }
+        let medial = chars
+            .get(i)
+            .copied()
+            .flatten()
+            .filter(|c| matches!(c, 'ㄧ' | 'ㄨ' | 'ㄩ'));
+        if medial.is_some() {
+            i += 1;
+        }
+        let rhyme = chars
+            .get(i)
+            .copied()
+            .flatten()
+            .filter(|c| ('ㄚ'..='ㄦ').contains(c));
+        if rhyme.is_some() {
+            i += 1;
+        }
+        if initial.is_none() && medial.is_none() && rhyme.is_none() {
+            return None;
+        }
+        let tone = if neutral {
+            Tone::Fifth
+        } else if let Some(t) = chars.get(i).copied().flatten().and_then(tone_mark) {
+            i += 1;
+            t
+        } else {
+            Tone::First
+        };
+
+        // a ㄦ not followed by a tone mark is taken as the erhua
+        let erhua = !(initial.is_none() && medial.is_none() && rhyme == Some('ㄦ'))
+            && chars.get(i) == Some(&Some('ㄦ'))
+            && chars
+                .get(i + 1)
+                .copied()
+                .flatten()
+                .and_then(tone_mark)
+                .is_none();
+        if erhua {
+            i += 1;
+        }
+        Some((
+            Self {
+                initial,
+                medial,
+                rhyme,
+                tone,
+                erhua,
+            },
+            i,
+        ))
+    }
+
+    /// The syllable as pinyin, such as `zhōng`; `None` if no pinyin spelling corresponds to it
+    fn to_pinyin(&self) -> Option<String> {
+        use NonRhoticFinal as F;
+        use SpellingInitial as I;
+        let fin = match (self.medial, self.rhyme) {
+            (None | Some('ㄧ'), None) => F::I,
+            (None, Some('ㄚ')) => F::A,
+            (None, Some('ㄛ')) => F::O,
+            (None, Some('ㄜ' | 'ㄦ')) => F::E,
+            (None, Some('ㄝ')) => F::Ê,
+            (None | Some('ㄧ'), Some('ㄞ')) => F::Ai,
+            (None, Some('ㄟ')) => F::Ei,
+            (None, Some('ㄠ')) => F::Ao,
+            (None, Some('ㄡ')) => F::Ou,
+            (None, Some('ㄢ')) => F::An,
+            (None, Some('ㄣ')) => F::En,
+            (None, Some('ㄤ')) => F::Ang,
+            (None, Some('ㄥ')) => F::Eng,
+            (Some('ㄧ'), Some('ㄚ')) => F::Ia,
+            (Some('ㄧ'), Some('ㄛ')) => F::Io,
+            (Some('ㄧ'), Some('ㄝ')) => F::Ie,
+            (Some('ㄧ'), Some('ㄠ')) => F::Iao,
+            (Some('ㄧ'), Some('ㄡ')) => F::Iu,
+            (Some('ㄧ'), Some('ㄢ')) => F::Ian,
+            (Some('ㄧ'), Some('ㄣ')) => F::In,
+            (Some('ㄧ'), Some('ㄤ')) => F::Iang,
+            (Some('ㄧ'), Some('ㄥ')) => F::Ing,
+            (Some('ㄨ'), None) => F::U,
+            (Some('ㄨ'), Some('ㄚ')) => F::Ua,
+            (Some('ㄨ'), Some('ㄛ')) => F::Uo,
+            (Some('ㄨ'), Some('ㄞ')) => F::Uai,
+            (Some('ㄨ'), Some('ㄟ')) => F::Ui,
+            (Some('ㄨ'), Some('ㄢ')) => F::Uan,
+            (Some('ㄨ'), Some('ㄣ')) => F::Un,
+            (Some('ㄨ'), Some('ㄤ')) => F::Uang,
+            (Some('ㄨ'), Some('ㄥ')) => F::Ong,
+            (Some('ㄩ'), None) => F::Ü,
+            (Some('ㄩ'), Some('ㄝ')) => F::Üe,
+            (Some('ㄩ'), Some('ㄢ')) => F::Üan,
+            (Some('ㄩ'), Some('ㄣ')) => F::Ün,
+            (Some('ㄩ'), Some('ㄥ')) => F::Iong,
+            _ => return None,
+        };
+
+        let (initial, fin) = match (self.initial, self.medial, fin) {
+            // the empty rhyme
+            (Some(initial @ (I::ZH | I::CH | I::SH | I::R | I::Z | I::C | I::S)), None, F::I)
+                if self.rhyme.is_none() =>
+            {
+                (initial, F::I)
+            }
+            // syllabic nasals
+            (Some(I::M), None, F::I) if self.rhyme.is_none() => (I::ZeroAEO, F::M),
+            (Some(I::N), None, F::I) if self.rhyme.is_none() => (I::ZeroAEO, F::N),
+            // `yai` has no final of its own
+            (_, None, F::I) | (Some(_), Some('ㄧ'), F::Ai) => return None,
+            (None, Some('ㄧ'), F::Ai) => (I::Y, F::Ai),
+
+            _ => finals::spelled(self.initial, fin),
+        };
+
+        Some(
+            Syllable {
+                initial,
+                fin,
+                tone: self.tone,
+                erhua: self.erhua || self.rhyme == Some('ㄦ'),
+                capitalized: false,
+                uppercase: 0,
+            }
+            .to_string(),
+        )
+    }
+}
+
+impl VecAndIndex<Result<PinyinToken, ParseError>> {
+    /// Replaces each syllable written in Zhuyin with the same syllable spelled in pinyin.
+    /// The first of the letters spans the whole Zhuyin syllable, and the rest are empty.
+    /// Zhuyin that corresponds to no pinyin syllable, such as `ㄅ` or `ㄅㄧㄞ`, is an error.
+    pub fn apply_zhuyin(&mut self, strictness: Strictness) {
+        let chars = self
+            .vec
+            .iter()
+            .map(|token| match token {
+                Ok(PinyinToken::Others(s)) => {
+                    let mut chars = s.chars();
+                    chars.next().filter(|_| chars.next().is_none())
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        self.replace_with(strictness, |this, i| {
+            let (syllable, len) = ZhuyinSyllable::read(&chars[i..])?;
+            let pinyin = syllable
+                .to_pinyin()
+                .ok_or_else(|| ParseError::InvalidZhuyin {
+                    found: chars[i..i + len].iter().flatten().collect(),
+                    span: this.span(i..i + len),
+                });
+            Some((len, pinyin))
+        });
+    }
+}